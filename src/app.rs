@@ -1,12 +1,38 @@
-use chrono::{offset::Local, Duration, NaiveDateTime, TimeZone};
+use chrono::{offset::Local, DateTime};
 use comfy_table::{presets::UTF8_FULL, Cell, CellAlignment, Table};
 use egui::{self, FontData, FontDefinitions, FontFamily, FontTweak};
 //use epi;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
-use trash::os_limited::{list, purge_all};
+use std::collections::{BTreeMap, HashSet};
+use std::ffi::OsString;
+use std::sync::Arc;
+use tracing::{debug, error, instrument, warn};
 
-use crate::{consts, CircularBuffer, GitHubInfo, NOTICE};
+use crate::audit::{AuditEntry, AuditLog, PurgeTrigger};
+use crate::backend::{SystemTrash, TrashBackend};
+use crate::config_file::ConfigFile;
+use crate::console::{
+    write_entries, Category, ConsoleContent, ConsoleEntry, ConsoleFilter, ConsoleFormat,
+    ConsoleTable, Level,
+};
+use crate::dashboard::{Dashboard, TrashSnapshot};
+use crate::diagnostics::{set_repaint_context, set_trace_level, take_traces};
+use crate::format::{format_date, format_size, set_date_settings, DateSettings, DateStyle};
+use crate::history::{history_window, HistoryDialog};
+use crate::i18n::{set_language, tr, trf, Language};
+use crate::profile::Profile;
+use crate::purge::purge_in_batches;
+use crate::quarantine::{Quarantine, QuarantinedItem};
+use crate::report::{
+    Candidate, ErrorKind, ExportFormat, ItemError, ItemRecord, ItemStatus, Report, ReportKind,
+    Selection, SelectionRule,
+};
+use crate::retention::{Retention, TimeUnit};
+use crate::rules::{protecting_rule, ProtectionRule};
+use crate::scheduler::{RunStatus, ScheduleConfig, ScheduleState, ScheduledRun};
+use crate::table::{show_details, ItemTable};
+use crate::worker::{Job, JobStatus, Progress};
+use crate::{consts, notice, CircularBuffer, GitHubInfo};
 
 impl<T> Serialize for CircularBuffer<T>
 where
@@ -17,7 +43,7 @@ where
         S: serde::Serializer,
     {
         let data = self.iter().collect::<Vec<&T>>();
-        let capacity = self.buffer.capacity();
+        let capacity = self.capacity();
         (capacity, data).serialize(serializer)
     }
 }
@@ -31,64 +57,156 @@ where
         D: serde::Deserializer<'de>,
     {
         let (capacity, data): (usize, Vec<T>) = Deserialize::deserialize(deserializer)?;
-        let mut buffer = CircularBuffer::new(capacity);
-
-        for item in data {
-            buffer.push(item);
-        }
-
+        let mut buffer = CircularBuffer::new(capacity.max(1));
+        buffer.extend(data);
         Ok(buffer)
     }
 }
 
-#[derive(serde::Deserialize, serde::Serialize)]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct ConfigApp {
-    time_threshold: u8,
-    max_console_lines: u16,
+    /// Durée pendant laquelle les éléments restent dans la corbeille.
+    pub(crate) time_threshold: Retention,
+    pub(crate) max_console_lines: u16,
+    pub(crate) size_quota_enabled: bool,
+    pub(crate) size_quota_gb: f64,
+    pub(crate) protection_rules: Vec<ProtectionRule>,
+    /// Au-delà de ce nombre d'éléments, la suppression doit être confirmée en tapant un mot.
+    pub(crate) confirm_count_threshold: u32,
+    /// Au-delà de cette taille (en Go), la suppression doit être confirmée en tapant un mot.
+    pub(crate) confirm_size_threshold_gb: f64,
+    pub(crate) schedule: ScheduleConfig,
+    /// Place les éléments en quarantaine au lieu de les supprimer définitivement.
+    pub(crate) quarantine_enabled: bool,
+    /// Nombre de jours passés en quarantaine avant la suppression définitive.
+    pub(crate) quarantine_days: u16,
+    pub(crate) date: DateSettings,
+    /// Politiques nommées. La politique en vigueur reste celle des champs ci-dessus.
+    pub(crate) profiles: Vec<Profile>,
+    /// Profil dont les modifications de la politique en vigueur sont enregistrées.
+    pub(crate) active_profile: Option<String>,
+    /// Niveau minimal des événements de diagnostic recopiés dans la console.
+    pub(crate) trace_level: Level,
 }
 
 impl Default for ConfigApp {
     fn default() -> Self {
         Self {
-            time_threshold: 30,
+            time_threshold: Retention::default(),
             max_console_lines: 1000,
+            size_quota_enabled: false,
+            size_quota_gb: 10.0,
+            protection_rules: Vec::new(),
+            confirm_count_threshold: 100,
+            confirm_size_threshold_gb: 5.0,
+            schedule: ScheduleConfig::default(),
+            quarantine_enabled: false,
+            quarantine_days: 30,
+            date: DateSettings::default(),
+            profiles: Vec::new(),
+            active_profile: None,
+            trace_level: Level::Info,
         }
     }
 }
 
+/// Fonction appelée avec chaque entrée ajoutée à la console.
+type ConsoleEcho = Box<dyn Fn(&ConsoleEntry) + Send>;
+
 #[derive(serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct ConsoleApp {
-    console_queue: CircularBuffer<String>,
+    entries: CircularBuffer<ConsoleEntry>,
+    /// Recopie vers la sortie standard ou vers le thread de l'interface.
+    #[serde(skip)]
+    echo: Option<ConsoleEcho>,
 }
 
 impl ConsoleApp {
-    pub fn get_last_console_messages(&self, count: usize) -> Vec<&String> {
-        if self.console_queue.is_empty() {
-            return Vec::new();
+    /// Console qui recopie chaque ligne sur la sortie standard (mode ligne de commande).
+    pub fn stdout() -> Self {
+        Self::forwarding(|entry| {
+            for line in entry.text().lines() {
+                println!("{}", line);
+            }
+        })
+    }
+
+    /// Console qui transmet chaque entrée ajoutée à `echo`.
+    pub fn forwarding(echo: impl Fn(&ConsoleEntry) + Send + 'static) -> Self {
+        Self {
+            echo: Some(Box::new(echo)),
+            ..Default::default()
         }
+    }
+
+    /// Entrées conservées, de la plus ancienne à la plus récente.
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &ConsoleEntry> {
+        self.entries.iter()
+    }
 
-        let messages = self.console_queue.iter().collect::<Vec<&String>>();
-        messages.into_iter().take(count).collect()
+    /// Change le nombre d'entrées conservées en gardant les plus récentes.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        if capacity != self.entries.capacity() {
+            self.entries.resize(capacity.max(1));
+        }
     }
 
-    pub fn add_to_buffer(&mut self, text: &str) {
-        if text.is_empty() {
+    pub fn push(&mut self, entry: ConsoleEntry) {
+        if entry.text().is_empty() {
             return;
         }
 
-        for line in text.lines() {
-            self.console_queue.push(line.to_string());
+        if let Some(echo) = &self.echo {
+            echo(&entry);
         }
+        self.entries.push(entry);
+    }
+
+    pub fn log(&mut self, level: Level, category: Category, text: &str) {
+        self.push(ConsoleEntry::new(
+            level,
+            category,
+            ConsoleContent::Message(text.to_owned()),
+        ));
+    }
+
+    pub fn info(&mut self, category: Category, text: &str) {
+        self.log(Level::Info, category, text);
+    }
+
+    pub fn warn(&mut self, category: Category, text: &str) {
+        self.log(Level::Warning, category, text);
+    }
+
+    pub fn error(&mut self, category: Category, text: &str) {
+        self.log(Level::Error, category, text);
+    }
+
+    /// Ajoute un tableau texte, affiché en police fixe.
+    pub fn table(&mut self, category: Category, table: &Table) {
+        self.push(ConsoleEntry::new(
+            Level::Info,
+            category,
+            ConsoleContent::Table(ConsoleTable::from(table)),
+        ));
+    }
+
+    /// Tableau le plus récent, celui du dernier rapport affiché.
+    pub fn last_table(&self) -> Option<&ConsoleTable> {
+        self.entries().rev().find_map(|entry| match &entry.content {
+            ConsoleContent::Table(table) => Some(table),
+            ConsoleContent::Message(_) => None,
+        })
     }
 
     pub fn _flush_storage(&mut self) {
-        if self.console_queue.is_empty() {
+        if self.entries.is_empty() {
             return;
         }
 
-        self.console_queue.clear();
+        self.entries.clear();
     }
 }
 
@@ -96,20 +214,268 @@ impl Default for ConsoleApp {
     fn default() -> Self {
         let config_app = ConfigApp::default();
         Self {
-            console_queue: CircularBuffer::new(config_app.max_console_lines.into()),
+            entries: CircularBuffer::new(config_app.max_console_lines.into()),
+            echo: None,
+        }
+    }
+}
+
+/// État de la fenêtre d'export du dernier rapport.
+pub struct ExportDialog {
+    open: bool,
+    format: ExportFormat,
+    path: String,
+    /// Le fichier existe: son remplacement attend une confirmation.
+    overwrite: bool,
+}
+
+impl Default for ExportDialog {
+    fn default() -> Self {
+        Self {
+            open: false,
+            format: ExportFormat::Json,
+            path: String::new(),
+            overwrite: false,
+        }
+    }
+}
+
+/// État de la fenêtre d'export de la console.
+pub struct ConsoleExportDialog {
+    open: bool,
+    format: ConsoleFormat,
+    path: String,
+    /// N'exporter que les entrées qui passent le filtre de la console.
+    filtered: bool,
+    /// Le fichier existe: son remplacement attend une confirmation.
+    overwrite: bool,
+}
+
+impl Default for ConsoleExportDialog {
+    fn default() -> Self {
+        Self {
+            open: false,
+            format: ConsoleFormat::Text,
+            path: String::new(),
+            filtered: true,
+            overwrite: false,
+        }
+    }
+}
+
+/// Sens de la fenêtre du fichier de configuration.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ConfigTransfer {
+    Import,
+    Export,
+}
+
+/// État de la fenêtre d'import et d'export de la configuration.
+#[derive(Default)]
+pub struct ConfigFileDialog {
+    /// `None` lorsque la fenêtre est fermée.
+    transfer: Option<ConfigTransfer>,
+    path: String,
+    /// Le fichier à exporter existe: son remplacement attend une confirmation.
+    overwrite: bool,
+}
+
+/// État de la fenêtre d'édition des règles de protection.
+#[derive(Default)]
+pub struct ProtectionDialog {
+    open: bool,
+    kind: usize,
+    pattern: String,
+}
+
+/// État de la fenêtre des profils.
+#[derive(Default)]
+pub struct ProfileDialog {
+    open: bool,
+    name: String,
+    /// Profil auquel comparer la politique en vigueur.
+    compare_with: Option<String>,
+}
+
+/// État de la fenêtre de la quarantaine.
+#[derive(Default)]
+pub struct QuarantineDialog {
+    open: bool,
+    items: Vec<QuarantinedItem>,
+    /// Élément dont la suppression définitive attend un second clic.
+    confirm_purge: Option<usize>,
+    error: Option<String>,
+}
+
+impl QuarantineDialog {
+    fn open(&mut self, quarantine: Option<&Quarantine>) {
+        self.open = true;
+        self.reload(quarantine);
+    }
+
+    fn reload(&mut self, quarantine: Option<&Quarantine>) {
+        self.items.clear();
+        match quarantine.map(Quarantine::list) {
+            Some(Ok(items)) => {
+                self.items = items;
+                self.error = None;
+            }
+            Some(Err(e)) => self.error = Some(trf("quarantine.list_error", &[&e])),
+            None => self.error = Some(tr("quarantine.unavailable").to_owned()),
+        }
+        self.confirm_purge = None;
+    }
+}
+
+/// Action demandée depuis la fenêtre de la quarantaine.
+enum QuarantineAction {
+    Reload,
+    Restore(usize),
+    Purge(usize),
+    PurgeExpired,
+}
+
+/// Suppression en attente de confirmation, avec son récapitulatif.
+pub struct PendingPurge {
+    candidates: Vec<Candidate>,
+    total_size: u64,
+    oldest: i64,
+    newest: i64,
+    typed: String,
+}
+
+impl PendingPurge {
+    /// Mot à taper pour confirmer les suppressions importantes.
+    fn confirmation_word() -> &'static str {
+        tr("confirm.word")
+    }
+
+    fn new(candidates: Vec<Candidate>) -> Self {
+        let times = candidates.iter().map(|c| c.item.time_deleted);
+        Self {
+            total_size: candidates.iter().filter_map(|c| c.size).sum(),
+            oldest: times.clone().min().unwrap_or_default(),
+            newest: times.max().unwrap_or_default(),
+            candidates,
+            typed: String::new(),
         }
     }
+
+    fn needs_typed_confirmation(&self, config_app: &ConfigApp) -> bool {
+        self.candidates.len() > config_app.confirm_count_threshold as usize
+            || self.total_size as f64 > config_app.confirm_size_threshold_gb * consts::BYTES_PER_GB
+    }
+}
+
+/// Résultat d'une opération exécutée en arrière-plan.
+enum JobOutput {
+    Report(Report),
+    /// Éléments à confirmer avant la suppression, `None` s'il n'y a rien à faire.
+    Candidates(Option<Vec<Candidate>>),
+    /// Exécution planifiée, avec son rapport si des éléments ont été traités.
+    Scheduled(ScheduledRun, Option<Report>),
+    /// Relevé pour le tableau de bord, `None` si la corbeille n'a pas pu être lue.
+    Snapshot(Option<TrashSnapshot>),
+    /// Fin de la suppression des éléments dont la quarantaine est terminée.
+    QuarantineExpired,
+}
+
+/// Onglet affiché au centre de la fenêtre.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+enum CentralTab {
+    /// Tableau du dernier rapport, ou la console s'il n'y en a pas.
+    #[default]
+    Items,
+    Dashboard,
 }
 
 /*
 We derive Deserialize/Serialize so we can persist app state on shutdown.
 if we add new fields, give them default values when deserializing old state
 */
-#[derive(serde::Deserialize, serde::Serialize, Default)]
+#[derive(serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct TemplateApp {
     config_app: ConfigApp,
     console_app: ConsoleApp,
+    schedule_state: ScheduleState,
+    language: Language,
+    #[serde(skip)]
+    backend: Arc<dyn TrashBackend>,
+    /// `None` si le dossier de données de l'application est introuvable.
+    #[serde(skip)]
+    quarantine: Option<Quarantine>,
+    /// `None` si le dossier de données de l'application est introuvable.
+    #[serde(skip)]
+    audit_log: Option<AuditLog>,
+    #[serde(skip)]
+    last_report: Option<Report>,
+    #[serde(skip)]
+    console_filter: ConsoleFilter,
+    #[serde(skip)]
+    export_dialog: ExportDialog,
+    #[serde(skip)]
+    console_export_dialog: ConsoleExportDialog,
+    #[serde(skip)]
+    config_file_dialog: ConfigFileDialog,
+    #[serde(skip)]
+    protection_dialog: ProtectionDialog,
+    #[serde(skip)]
+    profile_dialog: ProfileDialog,
+    #[serde(skip)]
+    history_dialog: HistoryDialog,
+    #[serde(skip)]
+    quarantine_dialog: QuarantineDialog,
+    #[serde(skip)]
+    item_table: ItemTable,
+    #[serde(skip)]
+    central_tab: CentralTab,
+    #[serde(skip)]
+    dashboard: Dashboard,
+    #[serde(skip)]
+    pending_purge: Option<PendingPurge>,
+    #[serde(skip)]
+    settings_open: bool,
+    #[serde(skip)]
+    job: Option<Job<JobOutput>>,
+    /// Début de l'exécution planifiée en cours, pour l'enregistrer même si
+    /// elle s'arrête sans résultat.
+    #[serde(skip)]
+    scheduled_job: Option<i64>,
+    /// Dernière vérification de la quarantaine expirée.
+    #[serde(skip)]
+    quarantine_checked: Option<DateTime<Local>>,
+}
+
+impl Default for TemplateApp {
+    fn default() -> Self {
+        Self {
+            config_app: ConfigApp::default(),
+            console_app: ConsoleApp::default(),
+            schedule_state: ScheduleState::default(),
+            language: Language::from_system(),
+            backend: Arc::new(SystemTrash),
+            quarantine: Quarantine::in_data_dir(),
+            audit_log: AuditLog::in_data_dir(),
+            last_report: None,
+            console_filter: ConsoleFilter::default(),
+            export_dialog: ExportDialog::default(),
+            console_export_dialog: ConsoleExportDialog::default(),
+            config_file_dialog: ConfigFileDialog::default(),
+            protection_dialog: ProtectionDialog::default(),
+            profile_dialog: ProfileDialog::default(),
+            history_dialog: HistoryDialog::default(),
+            quarantine_dialog: QuarantineDialog::default(),
+            item_table: ItemTable::default(),
+            central_tab: CentralTab::default(),
+            dashboard: Dashboard::default(),
+            pending_purge: None,
+            settings_open: false,
+            job: None,
+            scheduled_job: None,
+            quarantine_checked: None,
+        }
+    }
 }
 
 //################################# UI AREA ###################################
@@ -212,9 +578,17 @@ impl TemplateApp {
             Default::default()
         };
 
-        template_app.console_app.add_to_buffer(NOTICE);
+        set_language(template_app.language);
+        set_repaint_context(&cc.egui_ctx);
+        template_app.console_app.info(Category::General, notice());
         template_app
     }
+
+    /// Remplace la corbeille utilisée (par exemple par une `MemoryTrash`).
+    pub fn with_backend(mut self, backend: Arc<dyn TrashBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
 }
 
 //############################# UI PANEL AREA #################################
@@ -225,42 +599,299 @@ impl eframe::App for TemplateApp {
         let Self {
             config_app,
             console_app,
+            schedule_state,
+            language,
+            backend,
+            quarantine,
+            audit_log,
+            last_report,
+            console_filter,
+            export_dialog,
+            console_export_dialog,
+            config_file_dialog,
+            protection_dialog,
+            profile_dialog,
+            history_dialog,
+            quarantine_dialog,
+            item_table,
+            central_tab,
+            dashboard,
+            pending_purge,
+            settings_open,
+            job,
+            scheduled_job,
+            quarantine_checked,
         } = self;
+
+        set_date_settings(&config_app.date);
+        set_trace_level(config_app.trace_level);
+        console_app.set_capacity(config_app.max_console_lines.into());
+        for entry in take_traces() {
+            console_app.push(entry);
+        }
+
+        //______________________________ WORKER _______________________________
+        let status = match job {
+            Some(running) => running.poll(console_app),
+            None => JobStatus::Running,
+        };
+        match status {
+            JobStatus::Running => {}
+            JobStatus::Finished(output) => {
+                *job = None;
+                *scheduled_job = None;
+                match output {
+                    JobOutput::Report(report) => {
+                        *last_report = Some(report);
+                        item_table.reset();
+                        *central_tab = CentralTab::Items;
+                    }
+                    JobOutput::Candidates(candidates) => {
+                        *pending_purge = candidates.map(PendingPurge::new);
+                    }
+                    JobOutput::Scheduled(run, report) => {
+                        schedule_state.record(run);
+                        if let Some(report) = report {
+                            *last_report = Some(report);
+                            item_table.reset();
+                        }
+                    }
+                    JobOutput::Snapshot(snapshot) => {
+                        if let Some(snapshot) = snapshot {
+                            dashboard.set_snapshot(snapshot);
+                        }
+                    }
+                    JobOutput::QuarantineExpired => {
+                        if quarantine_dialog.open {
+                            quarantine_dialog.reload(quarantine.as_ref());
+                        }
+                    }
+                }
+            }
+            JobStatus::Failed => {
+                *job = None;
+                console_app.error(Category::General, tr("job.failed"));
+                // Sans trace, l'exécution resterait due et serait relancée aussitôt.
+                if let Some(time) = scheduled_job.take() {
+                    schedule_state.record(ScheduledRun {
+                        time,
+                        status: RunStatus::Failed,
+                        items: 0,
+                        size: 0,
+                        detail: tr("schedule.failed_detail").to_owned(),
+                    });
+                }
+            }
+        }
+
+        //_____________________________ SCHEDULER _____________________________
+        let now = Local::now();
+        // Quarantaine expirée: au démarrage puis à chaque passage du planificateur.
+        let tick = chrono::Duration::from_std(consts::SCHEDULER_TICK).unwrap_or_default();
+        if job.is_none() && quarantine_checked.map_or(true, |checked| now - checked >= tick) {
+            *quarantine_checked = Some(now);
+            if quarantine.is_some() {
+                *job = Some(spawn_quarantine_expiry(
+                    ctx,
+                    config_app,
+                    quarantine.as_ref(),
+                    audit_log.as_ref(),
+                ));
+            }
+        }
+        if job.is_none() && schedule_state.is_due(&config_app.schedule, now) {
+            let mut config_app = config_app.clone();
+            let backend = backend.clone();
+            let quarantine = quarantine.clone();
+            let audit_log = audit_log.clone();
+            *scheduled_job = Some(now.timestamp());
+            *job = Some(spawn_job(
+                ctx,
+                tr("schedule.title"),
+                true,
+                move |console_app, progress| {
+                    let (run, report) = suppression_planifiee(
+                        console_app,
+                        &mut config_app,
+                        backend.as_ref(),
+                        quarantine.as_ref(),
+                        audit_log.as_ref(),
+                        progress,
+                    );
+                    JobOutput::Scheduled(run, report)
+                },
+            ));
+        }
+        // Réveille l'interface pour le prochain passage même sans interaction.
+        let delay = schedule_state.next_run(&config_app.schedule).map_or(
+            consts::SCHEDULER_TICK,
+            |next_run| {
+                (next_run - now)
+                    .to_std()
+                    .unwrap_or_default()
+                    .min(consts::SCHEDULER_TICK)
+            },
+        );
+        ctx.request_repaint_after(delay);
+        let busy = job.is_some();
         //___________________________ TOPBOTTOMPANEL __________________________
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
-                ui.menu_button("File", |ui| {
-                    if ui.button("Quit").clicked() {
+                ui.menu_button(tr("menu.file"), |ui| {
+                    let export_button =
+                        ui.add_enabled(last_report.is_some(), egui::Button::new(tr("menu.export")));
+                    if export_button.clicked() {
+                        export_dialog.open = true;
+                        ui.close_menu();
+                    }
+                    if ui.button(tr("menu.export_console")).clicked() {
+                        console_export_dialog.open = true;
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui.button(tr("menu.import_config")).clicked() {
+                        config_file_dialog.transfer = Some(ConfigTransfer::Import);
+                        ui.close_menu();
+                    }
+                    if ui.button(tr("menu.export_config")).clicked() {
+                        config_file_dialog.transfer = Some(ConfigTransfer::Export);
+                        ui.close_menu();
+                    }
+                    ui.separator();
+                    if ui.button(tr("menu.history")).clicked() {
+                        history_dialog.open(audit_log.as_ref());
+                        ui.close_menu();
+                    }
+                    if ui.button(tr("menu.quarantine")).clicked() {
+                        quarantine_dialog.open(quarantine.as_ref());
+                        ui.close_menu();
+                    }
+                    if ui.button(tr("menu.settings")).clicked() {
+                        *settings_open = true;
+                        ui.close_menu();
+                    }
+                    if ui.button(tr("menu.quit")).clicked() {
                         frame.close();
                     }
                 });
             });
         });
+        //_____________________________ PROGRESS ______________________________
+        if let Some(running) = job {
+            egui::TopBottomPanel::bottom("job_panel").show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let (done, total) = running.progress().get();
+                    ui.label(running.label());
+                    let fraction = if total > 0 {
+                        done as f32 / total as f32
+                    } else {
+                        0.0
+                    };
+                    ui.add(
+                        egui::ProgressBar::new(fraction)
+                            .desired_width(300.0)
+                            .text(format!("{} / {}", done, total)),
+                    );
+                    if running.is_cancellable() {
+                        let cancelled = running.progress().is_cancelled();
+                        if ui
+                            .add_enabled(!cancelled, egui::Button::new(tr("button.cancel")))
+                            .clicked()
+                        {
+                            running.progress().cancel();
+                        }
+                    }
+                });
+            });
+        }
         //______________________________ SIDEPANEL ____________________________
         egui::SidePanel::left("side_panel")
             .resizable(false)
             .show(ctx, |ui| {
                 egui::warn_if_debug_build(ui);
                 ui.add_space(8.0);
+
+                // *** PROFILE SWITCHER ***
+                ui.with_layout(
+                    egui::Layout::top_down_justified(egui::Align::Center),
+                    |ui| {
+                        ui.add(egui::Label::new(tr("side.profile")));
+                    },
+                );
+                let mut switch_to = None;
+                egui::ComboBox::from_id_source("profile_switcher")
+                    .width(ui.available_width())
+                    .selected_text(config_app.policy_name())
+                    .show_ui(ui, |ui| {
+                        for profile in &config_app.profiles {
+                            let active =
+                                config_app.active_profile.as_deref() == Some(&profile.name);
+                            if ui.selectable_label(active, &profile.name).clicked() {
+                                switch_to = Some(profile.name.clone());
+                            }
+                        }
+                        ui.separator();
+                        if ui.selectable_label(false, tr("side.profiles")).clicked() {
+                            profile_dialog.open = true;
+                        }
+                    });
+                if let Some(name) = switch_to {
+                    config_app.switch_profile(&name);
+                    console_app.info(Category::Settings, &trf("profile.switched", &[&name]));
+                }
+                ui.add_space(8.0);
                 ui.with_layout(
                     egui::Layout::top_down_justified(egui::Align::Center),
                     |ui| {
-                        ui.add(egui::Label::new("Préserver"));
+                        ui.add(egui::Label::new(tr("side.keep")));
                     },
                 );
 
-                // *** DAYS USER INPUT ***
+                // *** RETENTION USER INPUT ***
+                ui.horizontal(|ui| {
+                    let retention = &mut config_app.time_threshold;
+                    ui.add(
+                        egui::DragValue::new(&mut retention.amount)
+                            .speed(0.1)
+                            .clamp_range(1..=9999),
+                    );
+                    egui::ComboBox::from_id_source("retention_unit")
+                        .width(80.0)
+                        .selected_text(retention.unit.label())
+                        .show_ui(ui, |ui| {
+                            for unit in TimeUnit::ALL {
+                                ui.selectable_value(&mut retention.unit, unit, unit.label());
+                            }
+                        });
+                });
+
+                // *** SIZE QUOTA USER INPUT ***
+                ui.add_space(8.0);
+                ui.checkbox(&mut config_app.size_quota_enabled, tr("side.size_quota"));
                 ui.with_layout(
                     egui::Layout::top_down_justified(egui::Align::Center),
                     |ui| {
-                        ui.add(
-                            egui::DragValue::new(&mut config_app.time_threshold)
+                        ui.add_enabled(
+                            config_app.size_quota_enabled,
+                            egui::DragValue::new(&mut config_app.size_quota_gb)
                                 .speed(0.1)
-                                .suffix(" jours")
-                                .clamp_range(1.0..=255.0),
+                                .suffix(tr("suffix.gb"))
+                                .clamp_range(0.0..=f64::MAX),
                         );
                     },
                 );
+
+                // *** BUTTON PROTECTIONS ***
+                ui.add_space(8.0);
+                ui.with_layout(
+                    egui::Layout::top_down_justified(egui::Align::Center),
+                    |ui| {
+                        let label = trf("side.protections", &[&config_app.protection_rules.len()]);
+                        if ui.button(label).clicked() {
+                            protection_dialog.open = true;
+                        }
+                    },
+                );
                 /*
                 // *** CLEAR CONSOLE QUEUE BUTTON ***
                 ui.add_space(8.0);
@@ -295,21 +926,52 @@ impl eframe::App for TemplateApp {
                 ui.with_layout(
                     egui::Layout::top_down_justified(egui::Align::Center),
                     |ui| {
-                        if ui.button("Analyser").clicked() {
-                            analyser(console_app, config_app);
+                        if ui
+                            .add_enabled(!busy, egui::Button::new(tr("side.analyze")))
+                            .clicked()
+                        {
+                            let mut config_app = config_app.clone();
+                            let backend = backend.clone();
+                            *job = Some(spawn_job(
+                                ctx,
+                                tr("job.analysis"),
+                                false,
+                                move |console_app, progress| {
+                                    JobOutput::Report(analyser(
+                                        console_app,
+                                        &mut config_app,
+                                        backend.as_ref(),
+                                        progress,
+                                    ))
+                                },
+                            ));
                         }
                     },
                 );
 
                 // *** BUTTON SUPPRIMER_DEFINITIVEMENT ***
                 ui.add_space(8.0);
-                let btn_label = "SUPPRIMER";
+                let btn_label = tr("side.purge");
                 ui.with_layout(
                     egui::Layout::top_down_justified(egui::Align::Center),
                     |ui| {
-                        let button_response = ui.button(btn_label);
+                        let button_response = ui.add_enabled(!busy, egui::Button::new(btn_label));
                         if button_response.clicked() {
-                            supprimer_definitivement(console_app, config_app);
+                            let mut config_app = config_app.clone();
+                            let backend = backend.clone();
+                            *job = Some(spawn_job(
+                                ctx,
+                                tr("job.analysis"),
+                                false,
+                                move |console_app, progress| {
+                                    JobOutput::Candidates(preparer_suppression(
+                                        console_app,
+                                        &mut config_app,
+                                        backend.as_ref(),
+                                        progress,
+                                    ))
+                                },
+                            ));
                         }
                     },
                 );
@@ -325,28 +987,214 @@ impl eframe::App for TemplateApp {
                         ui.label(consts::emojis::_GITHUB.to_string());
                         ui.add(egui::github_link_file!(
                             GitHubInfo::default().url_blob,
-                            "Code source"
+                            tr("side.source_code")
                         ));
                     });
                 });
             });
 
+        //____________________________EXPORT WINDOW____________________________
+        if let Some(report) = last_report {
+            export_window(ctx, export_dialog, console_app, report);
+        }
+        console_export_window(ctx, console_export_dialog, console_app, console_filter);
+
+        //_________________________CONFIG FILE WINDOW__________________________
+        config_file_window(ctx, config_file_dialog, config_app, language, console_app);
+
+        //___________________________CONFIRM WINDOW____________________________
+        if let Some(pending) = pending_purge {
+            match confirm_window(ctx, pending, config_app) {
+                Some(true) => {
+                    let candidates = std::mem::take(&mut pending.candidates);
+                    *pending_purge = None;
+                    let config_app = config_app.clone();
+                    let backend = backend.clone();
+                    let quarantine = quarantine.clone();
+                    let audit_log = audit_log.clone();
+                    *job = Some(spawn_job(
+                        ctx,
+                        tr("job.purge"),
+                        true,
+                        move |console_app, progress| {
+                            JobOutput::Report(supprimer_selection(
+                                console_app,
+                                &config_app,
+                                backend.as_ref(),
+                                quarantine.as_ref(),
+                                audit_log.as_ref(),
+                                candidates,
+                                progress,
+                            ))
+                        },
+                    ));
+                }
+                Some(false) => *pending_purge = None,
+                None => {}
+            }
+        }
+
+        //___________________________SETTINGS WINDOW___________________________
+        settings_window(ctx, settings_open, config_app, schedule_state, language);
+
+        //___________________________HISTORY WINDOW____________________________
+        history_window(ctx, history_dialog, audit_log.as_ref());
+
+        //_________________________QUARANTINE WINDOW___________________________
+        let purge_expired = quarantine_window(
+            ctx,
+            quarantine_dialog,
+            console_app,
+            config_app,
+            quarantine.as_ref(),
+            audit_log.as_ref(),
+            busy,
+        );
+        if purge_expired && job.is_none() {
+            *job = Some(spawn_quarantine_expiry(
+                ctx,
+                config_app,
+                quarantine.as_ref(),
+                audit_log.as_ref(),
+            ));
+        }
+
+        //__________________________PROTECTION WINDOW__________________________
+        protection_window(ctx, protection_dialog, config_app);
+
+        //____________________________PROFILE WINDOW___________________________
+        if let Some(other) = profile_window(ctx, profile_dialog, config_app, busy) {
+            let mut config_app = config_app.clone();
+            let backend = backend.clone();
+            *job = Some(spawn_job(
+                ctx,
+                tr("job.comparison"),
+                false,
+                move |console_app, progress| {
+                    JobOutput::Report(comparer_profils(
+                        console_app,
+                        &mut config_app,
+                        &other,
+                        backend.as_ref(),
+                        progress,
+                    ))
+                },
+            ));
+        }
+        config_app.sync_active_profile();
+
         //____________________________CENTRALPANEL_____________________________
+        // Avec un rapport ou le tableau de bord, la console passe en bas.
+        let mut table_action = None;
+        let mut refresh_dashboard = false;
+        let report = last_report
+            .as_ref()
+            .filter(|report| !report.items.is_empty());
+        if report.is_some() || *central_tab == CentralTab::Dashboard {
+            egui::TopBottomPanel::bottom("console_panel")
+                .resizable(true)
+                .default_height(140.0)
+                .show(ctx, |ui| {
+                    show_console(ui, console_app, console_filter);
+                });
+        }
+
+        let selected_record = report
+            .filter(|_| *central_tab == CentralTab::Items)
+            .and_then(|report| item_table.selected_record(report));
+        if let Some(record) = selected_record {
+            let mut close_details = false;
+            egui::SidePanel::right("details_panel")
+                .resizable(true)
+                .default_width(260.0)
+                .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.heading(tr("details.title"));
+                        close_details = ui.small_button("✖").clicked();
+                    });
+                    ui.separator();
+                    show_details(ui, record);
+                });
+            if close_details {
+                item_table.clear_selection();
+            }
+        }
+
         egui::CentralPanel::default().show(ctx, |ui| {
-            egui::ScrollArea::vertical()
-                .stick_to_bottom(true)
-                .show(ui, |ui| {
-                    let lines = self
-                        .console_app
-                        .get_last_console_messages(config_app.max_console_lines.into());
-                    let mut text = lines
+            // *** TABS ***
+            ui.horizontal(|ui| {
+                let items_label = match report {
+                    Some(_) => tr("tab.items"),
+                    None => tr("tab.console"),
+                };
+                ui.selectable_value(central_tab, CentralTab::Items, items_label);
+                let dashboard_tab =
+                    ui.selectable_value(central_tab, CentralTab::Dashboard, tr("tab.dashboard"));
+                // Premier affichage du tableau de bord: relevé automatique.
+                refresh_dashboard = dashboard_tab.clicked() && !dashboard.has_snapshot() && !busy;
+            });
+            ui.separator();
+
+            match (*central_tab, report) {
+                (CentralTab::Dashboard, _) => {
+                    refresh_dashboard |= dashboard.show(
+                        ui,
+                        config_app.time_threshold,
+                        &config_app.protection_rules,
+                        busy,
+                    );
+                }
+                (CentralTab::Items, Some(report)) => {
+                    if report.kind == ReportKind::Analysis {
+                        ui.add_enabled_ui(!busy, |ui| {
+                            table_action = selection_toolbar(ui, item_table, report);
+                        });
+                        ui.separator();
+                    } else if report
+                        .items
                         .iter()
-                        .map(|s| s.as_str())
-                        .collect::<Vec<&str>>()
-                        .join("\n");
-                    ui.add_sized(ui.available_size(), egui::TextEdit::multiline(&mut text));
-                });
+                        .any(|record| record.status == ItemStatus::Failed)
+                    {
+                        ui.add_enabled_ui(!busy, |ui| {
+                            table_action = retry_toolbar(ui, report);
+                        });
+                        ui.separator();
+                    }
+                    item_table.show(ui, report);
+                }
+                (CentralTab::Items, None) => show_console(ui, console_app, console_filter),
+            }
         });
+
+        if refresh_dashboard {
+            let backend = backend.clone();
+            *job = Some(spawn_job(
+                ctx,
+                tr("job.dashboard"),
+                false,
+                move |console_app, progress| {
+                    JobOutput::Snapshot(relever_corbeille(console_app, backend.as_ref(), progress))
+                },
+            ));
+        }
+
+        match table_action {
+            Some(TableAction::Purge(candidates)) => {
+                *pending_purge = Some(PendingPurge::new(candidates));
+            }
+            Some(TableAction::Restore(items)) => {
+                let backend = backend.clone();
+                *job = Some(spawn_job(
+                    ctx,
+                    tr("job.restore"),
+                    true,
+                    move |console_app, progress| {
+                        JobOutput::Report(restaurer(console_app, backend.as_ref(), items, progress))
+                    },
+                ));
+            }
+            None => {}
+        }
     }
 
     // Called by the frame work to save state before shutdown.
@@ -355,155 +1203,2306 @@ impl eframe::App for TemplateApp {
     }
 }
 
-//########################### BUTTONS FUNCTIONS AREA ##########################
+/// Lance une opération en arrière-plan qui redessine l'interface à chaque message.
+fn spawn_job(
+    ctx: &egui::Context,
+    label: &str,
+    cancellable: bool,
+    task: impl FnOnce(&mut ConsoleApp, &Progress) -> JobOutput + Send + 'static,
+) -> Job<JobOutput> {
+    let ctx = ctx.clone();
+    Job::spawn(label, cancellable, move || ctx.request_repaint(), task)
+}
 
-pub fn _clear_cache(storage: &mut dyn eframe::Storage) {
-    let none_ref: &Option<()> = &None;
-    eframe::set_value(storage, eframe::APP_KEY, none_ref);
+/// Lance en arrière-plan la suppression des éléments dont la quarantaine est terminée.
+fn spawn_quarantine_expiry(
+    ctx: &egui::Context,
+    config_app: &ConfigApp,
+    quarantine: Option<&Quarantine>,
+    audit_log: Option<&AuditLog>,
+) -> Job<JobOutput> {
+    let config_app = config_app.clone();
+    let quarantine = quarantine.cloned();
+    let audit_log = audit_log.cloned();
+    spawn_job(
+        ctx,
+        tr("job.quarantine_expiry"),
+        false,
+        move |console_app, _| {
+            supprimer_quarantaine_expiree(
+                console_app,
+                &config_app,
+                quarantine.as_ref(),
+                audit_log.as_ref(),
+            );
+            JobOutput::QuarantineExpired
+        },
+    )
 }
 
-//___________________FUNCTION BUTTON SUPPRIMER_DEFINITIVEMENT__________________
+/// Action demandée depuis la barre d'outils du tableau.
+enum TableAction {
+    Purge(Vec<Candidate>),
+    Restore(Vec<trash::TrashItem>),
+}
 
-fn supprimer_definitivement(console_app: &mut ConsoleApp, config_app: &mut ConfigApp) {
-    let elements_to_process = get_elements_to_process(console_app, config_app);
+fn selection_toolbar(
+    ui: &mut egui::Ui,
+    item_table: &mut ItemTable,
+    report: &Report,
+) -> Option<TableAction> {
+    let checked = item_table.checked_records(report);
+    // Les éléments protégés peuvent être restaurés mais jamais supprimés.
+    let purgeable = || {
+        checked
+            .iter()
+            .filter(|record| record.status == ItemStatus::Selected)
+    };
+    let purgeable_count = purgeable().count();
 
-    // Créez une nouvelle table avec le style UTF8_FULL
-    let mut table = Table::new();
-    table.load_preset(UTF8_FULL).set_header(vec![
-        Cell::new("Statut").set_alignment(CellAlignment::Left),
-        Cell::new("Nom du fichier").set_alignment(CellAlignment::Left),
-        Cell::new("Date de mise à la corbeille").set_alignment(CellAlignment::Left),
-    ]);
+    let mut action = None;
+    ui.horizontal(|ui| {
+        if ui.button(tr("toolbar.check_all")).clicked() {
+            item_table.check_all(report);
+        }
+        if ui.button(tr("toolbar.uncheck_all")).clicked() {
+            item_table.uncheck_all();
+        }
+        if ui.button(tr("toolbar.invert")).clicked() {
+            item_table.invert_checks(report);
+        }
+        ui.separator();
 
-    let mut success_count = 0;
-    let mut failure_count = 0;
-
-    for item in &elements_to_process {
-        let single_item_vec = vec![item.clone()];
-        let result = purge_all(single_item_vec);
-        use std::io::{Error, ErrorKind};
-        //let result: Result<(), Error> = Err(Error::new(ErrorKind::Other, "Échec simulé"));
-        let status_logo = if result.is_ok() {
-            success_count += 1;
-            "OK"
-        } else {
-            failure_count += 1;
-            "Oups !"
-        };
+        let purge_label = trf("toolbar.purge_selection", &[&purgeable_count]);
+        if ui
+            .add_enabled(purgeable_count > 0, egui::Button::new(purge_label))
+            .clicked()
+        {
+            let candidates = purgeable().map(|record| record.to_candidate()).collect();
+            action = Some(TableAction::Purge(candidates));
+        }
+        let restore_label = trf("toolbar.restore_selection", &[&checked.len()]);
+        if ui
+            .add_enabled(!checked.is_empty(), egui::Button::new(restore_label))
+            .clicked()
+        {
+            let items = checked.iter().map(|record| record.item.clone()).collect();
+            action = Some(TableAction::Restore(items));
+        }
+    });
+    action
+}
 
-        match NaiveDateTime::from_timestamp_opt(item.time_deleted, 0) {
-            Some(naive_datetime) => {
-                let time_deleted_local = Local.from_utc_datetime(&naive_datetime);
-                let formatted_time_deleted =
-                    time_deleted_local.format("%d.%m.%Y %H:%M").to_string();
-                table.add_row(vec![
-                    Cell::new(status_logo),
-                    Cell::new(&item.name),
-                    Cell::new(&formatted_time_deleted),
-                ]);
+/// Propose de relancer l'opération sur les éléments en échec.
+fn retry_toolbar(ui: &mut egui::Ui, report: &Report) -> Option<TableAction> {
+    let failed: Vec<&ItemRecord> = report
+        .items
+        .iter()
+        .filter(|record| record.status == ItemStatus::Failed)
+        .collect();
+
+    let mut action = None;
+    ui.horizontal(|ui| {
+        ui.label(trf("toolbar.failed_count", &[&failed.len()]));
+        if ui.button(tr("toolbar.retry")).clicked() {
+            action = Some(match report.kind {
+                ReportKind::Restore => {
+                    TableAction::Restore(failed.iter().map(|record| record.item.clone()).collect())
+                }
+                _ => {
+                    TableAction::Purge(failed.iter().map(|record| record.to_candidate()).collect())
+                }
+            });
+        }
+    });
+    action
+}
+
+fn show_console(ui: &mut egui::Ui, console_app: &ConsoleApp, console_filter: &mut ConsoleFilter) {
+    // *** FILTER BAR ***
+    ui.horizontal(|ui| {
+        for level in Level::ALL {
+            let mut label = egui::RichText::new(level.label());
+            if let Some(color) = level.color(ui.visuals()) {
+                label = label.color(color);
             }
-            None => {
-                console_app
-                    .add_to_buffer("**** Erreur lors de la conversion de l'horodatage ****\n");
-                return;
+            ui.toggle_value(&mut console_filter.levels[level as usize], label);
+        }
+        ui.separator();
+        egui::ComboBox::from_id_source("console_category")
+            .selected_text(
+                console_filter
+                    .category
+                    .map_or(tr("console.all_categories"), |category| category.label()),
+            )
+            .show_ui(ui, |ui| {
+                ui.selectable_value(
+                    &mut console_filter.category,
+                    None,
+                    tr("console.all_categories"),
+                );
+                for category in Category::ALL {
+                    ui.selectable_value(
+                        &mut console_filter.category,
+                        Some(category),
+                        category.label(),
+                    );
+                }
+            });
+        ui.separator();
+        ui.add(
+            egui::TextEdit::singleline(&mut console_filter.search).hint_text(tr("console.search")),
+        );
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let last_table = console_app.last_table();
+            let copy_button = ui
+                .add_enabled(
+                    last_table.is_some(),
+                    egui::Button::new(tr("console.copy_report")),
+                )
+                .on_hover_text(tr("console.copy_report_hint"));
+            if copy_button.clicked() {
+                if let Some(table) = last_table {
+                    ui.output_mut(|output| output.copied_text = table.text.clone());
+                }
             }
-        };
-    }
-    console_app.add_to_buffer("\n\nSUPPRESSION DÉFINITIVE\n\n");
-    console_app.add_to_buffer(&table.to_string());
+        });
+    });
+    ui.separator();
 
-    let mut stats_table = Table::new();
-    stats_table.load_preset(UTF8_FULL).set_header(vec![
-        Cell::new("Statut").set_alignment(CellAlignment::Left),
-        Cell::new("Nombre").set_alignment(CellAlignment::Left),
-    ]);
+    egui::ScrollArea::vertical()
+        .stick_to_bottom(true)
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            egui::Grid::new("console_entries")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    for entry in console_app
+                        .entries()
+                        .filter(|entry| console_filter.matches(entry))
+                    {
+                        show_console_entry(ui, entry);
+                        ui.end_row();
+                    }
+                });
+        });
+}
 
-    stats_table.add_row(vec![
-        Cell::new("Succès"),
-        Cell::new(success_count.to_string()),
-    ]);
+/// Une ligne de la console: heure, niveau, catégorie puis message ou tableau.
+fn show_console_entry(ui: &mut egui::Ui, entry: &ConsoleEntry) {
+    let time = DateTime::from_timestamp(entry.timestamp, 0)
+        .map(|time| time.with_timezone(&Local).format("%H:%M:%S").to_string())
+        .unwrap_or_default();
+    ui.label(egui::RichText::new(time).monospace().weak())
+        .on_hover_text(format_date(entry.timestamp));
 
-    stats_table.add_row(vec![
-        Cell::new("Échec"),
-        Cell::new(failure_count.to_string()),
-    ]);
+    let color = entry.level.color(ui.visuals());
+    let mut level = egui::RichText::new(entry.level.label()).strong();
+    if let Some(color) = color {
+        level = level.color(color);
+    }
+    ui.label(level);
+    ui.label(egui::RichText::new(entry.category.label()).weak());
 
-    console_app.add_to_buffer("\nStatistiques de suppression:\n");
-    console_app.add_to_buffer(&stats_table.to_string());
+    let text = entry.text().trim_matches('\n');
+    let mut text = match entry.content {
+        ConsoleContent::Message(_) => egui::RichText::new(text),
+        ConsoleContent::Table(_) => egui::RichText::new(text).monospace(),
+    };
+    if let Some(color) = color {
+        text = text.color(color);
+    }
+    ui.label(text).context_menu(|ui| {
+        if ui.button(tr("console.copy")).clicked() {
+            let copied = entry.text().trim_matches('\n').to_owned();
+            ui.output_mut(|output| output.copied_text = copied);
+            ui.close_menu();
+        }
+    });
 }
 
-//__________________________FUNCTION BUTTON ANALYSER___________________________
-fn analyser(console_app: &mut ConsoleApp, config_app: &mut ConfigApp) {
-    let elements_to_process = get_elements_to_process(console_app, config_app);
+//########################### BUTTONS FUNCTIONS AREA ##########################
 
-    let mut table = Table::new();
+/// Résultat d'une analyse ou d'une suppression, utilisé pour les codes de sortie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Success,
+    PartialFailure,
+    ListingError,
+    /// L'utilisateur a interrompu l'opération avant la fin.
+    Cancelled,
+}
+
+pub fn _clear_cache(storage: &mut dyn eframe::Storage) {
+    let none_ref: &Option<()> = &None;
+    eframe::set_value(storage, eframe::APP_KEY, none_ref);
+}
+
+//_____________________________FUNCTION EXPORT_________________________________
+
+fn export_window(
+    ctx: &egui::Context,
+    export_dialog: &mut ExportDialog,
+    console_app: &mut ConsoleApp,
+    report: &Report,
+) {
+    let mut open = export_dialog.open;
+    let mut close = false;
+    egui::Window::new(tr("export.title"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            let previous_format = export_dialog.format;
+            egui::ComboBox::from_label(tr("export.format"))
+                .selected_text(export_dialog.format.label())
+                .show_ui(ui, |ui| {
+                    for format in ExportFormat::ALL {
+                        ui.selectable_value(&mut export_dialog.format, format, format.label());
+                    }
+                });
+
+            if export_dialog.path.is_empty() {
+                export_dialog.path = format!(
+                    "{}.{}",
+                    tr("export.default_name"),
+                    export_dialog.format.extension()
+                );
+            } else if export_dialog.format != previous_format {
+                export_dialog.path = std::path::Path::new(&export_dialog.path)
+                    .with_extension(export_dialog.format.extension())
+                    .to_string_lossy()
+                    .into_owned();
+                export_dialog.overwrite = false;
+            }
+            ui.horizontal(|ui| {
+                ui.label(tr("export.file"));
+                if ui.text_edit_singleline(&mut export_dialog.path).changed() {
+                    export_dialog.overwrite = false;
+                }
+            });
+
+            if save_button(
+                ui,
+                tr("export.save"),
+                &export_dialog.path,
+                &mut export_dialog.overwrite,
+            ) {
+                let result = std::fs::File::create(&export_dialog.path)
+                    .and_then(|file| report.write_to(export_dialog.format, file));
+                match result {
+                    Ok(()) => console_app.info(
+                        Category::Export,
+                        &trf("export.done", &[&export_dialog.path]),
+                    ),
+                    Err(e) => console_app.error(Category::Export, &trf("export.error", &[&e])),
+                }
+                close = true;
+            }
+        });
+    export_dialog.open = open && !close;
+    if !export_dialog.open {
+        export_dialog.overwrite = false;
+    }
+}
+
+//_________________________FUNCTION CONSOLE EXPORT_____________________________
+
+fn console_export_window(
+    ctx: &egui::Context,
+    console_export_dialog: &mut ConsoleExportDialog,
+    console_app: &mut ConsoleApp,
+    console_filter: &ConsoleFilter,
+) {
+    let mut open = console_export_dialog.open;
+    let mut close = false;
+    egui::Window::new(tr("console_export.title"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            let previous_format = console_export_dialog.format;
+            egui::ComboBox::from_label(tr("export.format"))
+                .selected_text(console_export_dialog.format.label())
+                .show_ui(ui, |ui| {
+                    for format in ConsoleFormat::ALL {
+                        ui.selectable_value(
+                            &mut console_export_dialog.format,
+                            format,
+                            format.label(),
+                        );
+                    }
+                });
+
+            if console_export_dialog.path.is_empty() {
+                console_export_dialog.path = format!(
+                    "{}.{}",
+                    tr("console_export.default_name"),
+                    console_export_dialog.format.extension()
+                );
+            } else if console_export_dialog.format != previous_format {
+                console_export_dialog.path = std::path::Path::new(&console_export_dialog.path)
+                    .with_extension(console_export_dialog.format.extension())
+                    .to_string_lossy()
+                    .into_owned();
+                console_export_dialog.overwrite = false;
+            }
+            ui.horizontal(|ui| {
+                ui.label(tr("export.file"));
+                if ui
+                    .text_edit_singleline(&mut console_export_dialog.path)
+                    .changed()
+                {
+                    console_export_dialog.overwrite = false;
+                }
+            });
+            ui.checkbox(
+                &mut console_export_dialog.filtered,
+                tr("console_export.filtered"),
+            );
+
+            if save_button(
+                ui,
+                tr("export.save"),
+                &console_export_dialog.path,
+                &mut console_export_dialog.overwrite,
+            ) {
+                let filtered = console_export_dialog.filtered;
+                let entries = console_app
+                    .entries()
+                    .filter(|entry| !filtered || console_filter.matches(entry));
+                let result = std::fs::File::create(&console_export_dialog.path)
+                    .map(std::io::BufWriter::new)
+                    .and_then(|file| write_entries(entries, console_export_dialog.format, file));
+                match result {
+                    Ok(()) => console_app.info(
+                        Category::Export,
+                        &trf("console_export.done", &[&console_export_dialog.path]),
+                    ),
+                    Err(e) => {
+                        console_app.error(Category::Export, &trf("console_export.error", &[&e]))
+                    }
+                }
+                close = true;
+            }
+        });
+    console_export_dialog.open = open && !close;
+    if !console_export_dialog.open {
+        console_export_dialog.overwrite = false;
+    }
+}
+
+/// Bouton d'enregistrement qui demande confirmation avant de remplacer un
+/// fichier existant. Retourne `true` lorsque le fichier peut être écrit.
+fn save_button(ui: &mut egui::Ui, label: &str, path: &str, overwrite: &mut bool) -> bool {
+    if !*overwrite {
+        if !ui.button(label).clicked() {
+            return false;
+        }
+        *overwrite = std::path::Path::new(path).exists();
+        return !*overwrite;
+    }
+    ui.colored_label(
+        ui.visuals().warn_fg_color,
+        trf("export.overwrite", &[&path]),
+    );
+    ui.horizontal(|ui| {
+        let confirmed = ui.button(tr("export.replace")).clicked();
+        if confirmed || ui.button(tr("button.cancel")).clicked() {
+            *overwrite = false;
+        }
+        confirmed
+    })
+    .inner
+}
+
+//__________________________FUNCTION CONFIG FILE_______________________________
+
+fn config_file_window(
+    ctx: &egui::Context,
+    config_file_dialog: &mut ConfigFileDialog,
+    config_app: &mut ConfigApp,
+    language: &mut Language,
+    console_app: &mut ConsoleApp,
+) {
+    let Some(transfer) = config_file_dialog.transfer else {
+        return;
+    };
+    let (title, action) = match transfer {
+        ConfigTransfer::Import => (tr("config.import_title"), tr("config.import")),
+        ConfigTransfer::Export => (tr("config.export_title"), tr("export.save")),
+    };
+    let mut open = true;
+    let mut close = false;
+    egui::Window::new(title)
+        .id(egui::Id::new("config_file_window"))
+        .open(&mut open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            if config_file_dialog.path.is_empty() {
+                config_file_dialog.path = tr("config.default_name").to_owned();
+            }
+            ui.horizontal(|ui| {
+                ui.label(tr("export.file"));
+                if ui
+                    .text_edit_singleline(&mut config_file_dialog.path)
+                    .changed()
+                {
+                    config_file_dialog.overwrite = false;
+                }
+            });
+
+            let confirmed = match transfer {
+                ConfigTransfer::Import => ui.button(action).clicked(),
+                ConfigTransfer::Export => save_button(
+                    ui,
+                    action,
+                    &config_file_dialog.path,
+                    &mut config_file_dialog.overwrite,
+                ),
+            };
+            if confirmed {
+                let path = std::path::Path::new(&config_file_dialog.path);
+                match transfer {
+                    ConfigTransfer::Import => match ConfigFile::load(path) {
+                        Ok(config_file) => {
+                            *config_app = config_file.config;
+                            *language = config_file.language;
+                            set_language(*language);
+                            console_app.info(
+                                Category::Settings,
+                                &trf("config.imported", &[&config_file_dialog.path]),
+                            );
+                        }
+                        Err(e) => console_app
+                            .error(Category::Settings, &trf("config.import_error", &[&e])),
+                    },
+                    ConfigTransfer::Export => {
+                        match ConfigFile::new(config_app, *language).save(path) {
+                            Ok(()) => console_app.info(
+                                Category::Settings,
+                                &trf("config.exported", &[&config_file_dialog.path]),
+                            ),
+                            Err(e) => console_app
+                                .error(Category::Settings, &trf("config.export_error", &[&e])),
+                        }
+                    }
+                }
+                close = true;
+            }
+        });
+    if !open || close {
+        config_file_dialog.transfer = None;
+        config_file_dialog.overwrite = false;
+    }
+}
+
+//____________________________FUNCTION SETTINGS________________________________
+
+fn settings_window(
+    ctx: &egui::Context,
+    open: &mut bool,
+    config_app: &mut ConfigApp,
+    schedule_state: &ScheduleState,
+    language: &mut Language,
+) {
+    egui::Window::new(tr("settings.title"))
+        .open(open)
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(tr("settings.language"));
+                egui::ComboBox::from_id_source("settings_language")
+                    .selected_text(language.label())
+                    .show_ui(ui, |ui| {
+                        for option in Language::ALL {
+                            if ui
+                                .selectable_value(language, option, option.label())
+                                .changed()
+                            {
+                                set_language(option);
+                            }
+                        }
+                    });
+            });
+
+            ui.separator();
+            ui.heading(tr("settings.console"));
+            ui.horizontal(|ui| {
+                ui.label(tr("settings.console_entries"));
+                ui.add(
+                    egui::DragValue::new(&mut config_app.max_console_lines)
+                        .speed(10.0)
+                        .clamp_range(10..=u16::MAX),
+                );
+            });
+            ui.horizontal(|ui| {
+                ui.label(tr("settings.trace_level"));
+                egui::ComboBox::from_id_source("settings_trace_level")
+                    .selected_text(config_app.trace_level.label())
+                    .show_ui(ui, |ui| {
+                        for level in Level::ALL {
+                            ui.selectable_value(&mut config_app.trace_level, level, level.label());
+                        }
+                    });
+            });
+
+            ui.separator();
+            ui.heading(tr("settings.dates"));
+            let date = &mut config_app.date;
+            egui::Grid::new("settings_dates")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label(tr("settings.date_format"));
+                    egui::ComboBox::from_id_source("settings_date_style")
+                        .selected_text(date.style.label())
+                        .show_ui(ui, |ui| {
+                            for style in DateStyle::ALL {
+                                ui.selectable_value(&mut date.style, style, style.label());
+                            }
+                        });
+                    ui.end_row();
+
+                    if date.style == DateStyle::Custom {
+                        ui.label(tr("settings.custom_format"));
+                        ui.text_edit_singleline(&mut date.custom);
+                        ui.end_row();
+                    }
+                });
+            if date.style == DateStyle::Custom && !date.is_valid_custom() {
+                ui.colored_label(ui.visuals().error_fg_color, tr("settings.invalid_format"));
+            }
+            ui.checkbox(&mut date.utc, tr("settings.utc"));
+            ui.checkbox(&mut date.relative, tr("settings.relative"));
+            set_date_settings(date);
+            let three_days_ago = Local::now().timestamp() - 3 * 86_400;
+            ui.label(trf(
+                "settings.date_preview",
+                &[&format_date(three_days_ago)],
+            ));
+
+            ui.separator();
+            ui.heading(tr("settings.confirmation"));
+            ui.label(tr("settings.confirmation_hint"));
+            egui::Grid::new("settings_confirmation")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label(tr("settings.item_count"));
+                    ui.add(
+                        egui::DragValue::new(&mut config_app.confirm_count_threshold)
+                            .speed(1.0)
+                            .suffix(tr("suffix.items")),
+                    );
+                    ui.end_row();
+
+                    ui.label(tr("settings.total_size"));
+                    ui.add(
+                        egui::DragValue::new(&mut config_app.confirm_size_threshold_gb)
+                            .speed(0.1)
+                            .suffix(tr("suffix.gb"))
+                            .clamp_range(0.0..=f64::MAX),
+                    );
+                    ui.end_row();
+                });
+
+            ui.separator();
+            ui.heading(tr("quarantine.title"));
+            ui.checkbox(
+                &mut config_app.quarantine_enabled,
+                tr("settings.quarantine_enabled"),
+            );
+            ui.add_enabled_ui(config_app.quarantine_enabled, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(tr("settings.duration"));
+                    ui.add(
+                        egui::DragValue::new(&mut config_app.quarantine_days)
+                            .clamp_range(1..=3650)
+                            .suffix(tr("suffix.days")),
+                    );
+                });
+            });
+
+            ui.separator();
+            ui.heading(tr("schedule.title"));
+            let schedule = &mut config_app.schedule;
+            ui.checkbox(&mut schedule.enabled, tr("settings.schedule_enabled"));
+            ui.add_enabled_ui(schedule.enabled, |ui| {
+                egui::Grid::new("settings_schedule")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label(tr("settings.time"));
+                        ui.horizontal(|ui| {
+                            ui.add(egui::DragValue::new(&mut schedule.hour).clamp_range(0..=23));
+                            ui.label(":");
+                            ui.add(egui::DragValue::new(&mut schedule.minute).clamp_range(0..=59));
+                        });
+                        ui.end_row();
+
+                        ui.label(tr("settings.every"));
+                        ui.add(
+                            egui::DragValue::new(&mut schedule.interval_days)
+                                .clamp_range(1..=365)
+                                .suffix(tr("suffix.days")),
+                        );
+                        ui.end_row();
+
+                        ui.label(tr("settings.max_items"));
+                        ui.add(
+                            egui::DragValue::new(&mut schedule.max_items)
+                                .speed(1.0)
+                                .suffix(tr("suffix.items")),
+                        );
+                        ui.end_row();
+
+                        ui.label(tr("settings.max_size"));
+                        ui.add(
+                            egui::DragValue::new(&mut schedule.max_size_gb)
+                                .speed(0.1)
+                                .suffix(tr("suffix.gb"))
+                                .clamp_range(0.0..=f64::MAX),
+                        );
+                        ui.end_row();
+                    });
+                ui.label(tr("settings.limits_hint"));
+                if let Some(next_run) = schedule_state.next_run(schedule) {
+                    ui.label(trf(
+                        "schedule.next_run",
+                        &[&format_date(next_run.timestamp())],
+                    ));
+                }
+            });
+
+            if !schedule_state.runs.is_empty() {
+                ui.add_space(4.0);
+                ui.label(tr("settings.last_runs"));
+                egui::ScrollArea::vertical()
+                    .max_height(150.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("schedule_runs")
+                            .num_columns(4)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for run in schedule_state.runs.iter().rev() {
+                                    ui.label(format_date(run.time));
+                                    ui.label(run.status.label());
+                                    ui.label(trf(
+                                        "schedule.run_summary",
+                                        &[&run.items, &format_size(Some(run.size))],
+                                    ));
+                                    ui.label(&run.detail);
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            }
+        });
+}
+
+//_____________________________FUNCTION CONFIRM________________________________
+
+/// Sélectionne les éléments dont la suppression définitive doit être confirmée.
+fn preparer_suppression(
+    console_app: &mut ConsoleApp,
+    config_app: &mut ConfigApp,
+    backend: &dyn TrashBackend,
+    progress: &Progress,
+) -> Option<Vec<Candidate>> {
+    let candidates = get_elements_to_process(console_app, config_app, backend, progress)
+        .ok()?
+        .candidates;
+    if candidates.is_empty() {
+        console_app.info(Category::Purge, &format!("\n{}\n", tr("purge.nothing")));
+        return None;
+    }
+    Some(candidates)
+}
+
+/// Fenêtre de confirmation. Retourne `Some(true)` si la suppression est
+/// confirmée, `Some(false)` si elle est annulée.
+fn confirm_window(
+    ctx: &egui::Context,
+    pending: &mut PendingPurge,
+    config_app: &ConfigApp,
+) -> Option<bool> {
+    let mut decision = None;
+    let needs_typed_confirmation = pending.needs_typed_confirmation(config_app);
+    egui::Window::new(tr("confirm.title"))
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            if config_app.quarantine_enabled {
+                ui.label(trf("confirm.quarantine", &[&config_app.quarantine_days]));
+            } else {
+                ui.label(tr("confirm.purge"));
+            }
+            ui.add_space(4.0);
+            egui::Grid::new("confirm_summary")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label(tr("settings.item_count"));
+                    ui.strong(pending.candidates.len().to_string());
+                    ui.end_row();
+                    ui.label(tr("settings.total_size"));
+                    ui.strong(format_size(Some(pending.total_size)));
+                    ui.end_row();
+                    ui.label(tr("confirm.oldest"));
+                    ui.strong(format_date(pending.oldest));
+                    ui.end_row();
+                    ui.label(tr("confirm.newest"));
+                    ui.strong(format_date(pending.newest));
+                    ui.end_row();
+                });
+            ui.add_space(4.0);
+            if config_app.quarantine_enabled {
+                ui.label(tr("confirm.restorable"));
+            } else {
+                ui.colored_label(ui.visuals().warn_fg_color, tr("confirm.irreversible"));
+            }
+
+            if needs_typed_confirmation {
+                ui.label(trf(
+                    "confirm.type_word",
+                    &[&PendingPurge::confirmation_word()],
+                ));
+                ui.text_edit_singleline(&mut pending.typed);
+            }
+            let confirmed = !needs_typed_confirmation
+                || pending.typed.trim() == PendingPurge::confirmation_word();
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button(tr("button.cancel")).clicked() {
+                    decision = Some(false);
+                }
+                if ui
+                    .add_enabled(confirmed, egui::Button::new(tr("confirm.purge_button")))
+                    .clicked()
+                {
+                    decision = Some(true);
+                }
+            });
+        });
+    decision
+}
+
+//__________________________FUNCTION PROTECTIONS_______________________________
+
+fn protection_window(
+    ctx: &egui::Context,
+    protection_dialog: &mut ProtectionDialog,
+    config_app: &mut ConfigApp,
+) {
+    let mut open = protection_dialog.open;
+    egui::Window::new(tr("protection.title"))
+        .open(&mut open)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.label(tr("protection.hint"));
+            ui.separator();
+
+            let mut to_remove = None;
+            egui::Grid::new("protection_rules")
+                .striped(true)
+                .show(ui, |ui| {
+                    for (index, rule) in config_app.protection_rules.iter().enumerate() {
+                        ui.label(rule.kind_label());
+                        ui.label(rule.pattern());
+                        if ui.small_button(tr("protection.remove")).clicked() {
+                            to_remove = Some(index);
+                        }
+                        ui.end_row();
+                    }
+                });
+            if let Some(index) = to_remove {
+                config_app.protection_rules.remove(index);
+            }
+            ui.separator();
+
+            let error = ui.horizontal(|ui| {
+                let kinds = ProtectionRule::kinds();
+                egui::ComboBox::from_id_source("protection_kind")
+                    .selected_text(kinds[protection_dialog.kind])
+                    .show_ui(ui, |ui| {
+                        for (index, label) in kinds.into_iter().enumerate() {
+                            ui.selectable_value(&mut protection_dialog.kind, index, label);
+                        }
+                    });
+                ui.text_edit_singleline(&mut protection_dialog.pattern);
+                let rule =
+                    ProtectionRule::from_kind(protection_dialog.kind, &protection_dialog.pattern);
+                let error = rule.validate().err();
+                let can_add = !rule.pattern().is_empty() && error.is_none();
+                if ui
+                    .add_enabled(can_add, egui::Button::new(tr("protection.add")))
+                    .clicked()
+                {
+                    config_app.protection_rules.push(rule);
+                    protection_dialog.pattern.clear();
+                }
+                error
+            });
+            if let Some(error) = error.inner {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        });
+    protection_dialog.open = open;
+}
+
+//____________________________FUNCTION PROFILES________________________________
+
+/// Fenêtre de gestion des profils. Retourne le profil à comparer avec la
+/// politique en vigueur lorsque la comparaison est demandée.
+fn profile_window(
+    ctx: &egui::Context,
+    profile_dialog: &mut ProfileDialog,
+    config_app: &mut ConfigApp,
+    busy: bool,
+) -> Option<Profile> {
+    let mut open = profile_dialog.open;
+    let mut compare = None;
+    egui::Window::new(tr("profile.title"))
+        .open(&mut open)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            ui.label(tr("profile.hint"));
+            ui.separator();
+
+            let mut to_switch = None;
+            let mut to_remove = None;
+            egui::Grid::new("profiles").striped(true).show(ui, |ui| {
+                for profile in &config_app.profiles {
+                    let active = config_app.active_profile.as_deref() == Some(&profile.name);
+                    if ui.radio(active, &profile.name).clicked() {
+                        to_switch = Some(profile.name.clone());
+                    }
+                    ui.label(profile.describe());
+                    if ui.small_button(tr("profile.remove")).clicked() {
+                        to_remove = Some(profile.name.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+            if let Some(name) = to_switch {
+                config_app.switch_profile(&name);
+            }
+            if let Some(name) = to_remove {
+                config_app.remove_profile(&name);
+            }
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label(tr("profile.name"));
+                ui.text_edit_singleline(&mut profile_dialog.name);
+                let name = profile_dialog.name.trim();
+                if ui
+                    .add_enabled(!name.is_empty(), egui::Button::new(tr("profile.save")))
+                    .clicked()
+                {
+                    config_app.save_profile(name);
+                    profile_dialog.name.clear();
+                }
+            });
+            ui.separator();
+
+            // *** COMPARAISON ***
+            ui.horizontal(|ui| {
+                ui.label(trf("profile.compare_with", &[&config_app.policy_name()]));
+                let others = config_app
+                    .profiles
+                    .iter()
+                    .filter(|profile| config_app.active_profile.as_ref() != Some(&profile.name));
+                if profile_dialog
+                    .compare_with
+                    .as_ref()
+                    .map_or(false, |name| config_app.profile(name).is_none())
+                {
+                    profile_dialog.compare_with = None;
+                }
+                egui::ComboBox::from_id_source("profile_compare")
+                    .selected_text(profile_dialog.compare_with.as_deref().unwrap_or_default())
+                    .show_ui(ui, |ui| {
+                        for profile in others {
+                            ui.selectable_value(
+                                &mut profile_dialog.compare_with,
+                                Some(profile.name.clone()),
+                                &profile.name,
+                            );
+                        }
+                    });
+                let other = profile_dialog
+                    .compare_with
+                    .as_deref()
+                    .and_then(|name| config_app.profile(name));
+                if ui
+                    .add_enabled(
+                        !busy && other.is_some(),
+                        egui::Button::new(tr("profile.compare")),
+                    )
+                    .clicked()
+                {
+                    compare = other.cloned();
+                }
+            });
+        });
+    profile_dialog.open = open;
+    compare
+}
+
+//____________________________FUNCTION RESTORE_________________________________
+
+#[instrument(name = "restore", skip_all, fields(items = items.len()))]
+fn restaurer(
+    console_app: &mut ConsoleApp,
+    backend: &dyn TrashBackend,
+    items: Vec<trash::TrashItem>,
+    progress: &Progress,
+) -> Report {
+    let mut report = Report::new(ReportKind::Restore);
+    let total = items.len();
+
+    let mut table = Table::new();
     table.load_preset(UTF8_FULL).set_header(vec![
-        Cell::new("Nom de l'élément").set_alignment(CellAlignment::Left),
-        Cell::new("Date de suppression").set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.status")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.file_name")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.original_folder")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.cause")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.detail")).set_alignment(CellAlignment::Left),
     ]);
 
-    for item in &elements_to_process {
-        match NaiveDateTime::from_timestamp_opt(item.time_deleted, 0) {
-            Some(naive_datetime) => {
-                let time_deleted_local = Local.from_utc_datetime(&naive_datetime);
-                let formatted_time_deleted =
-                    time_deleted_local.format("%d.%m.%Y %H:%M").to_string();
+    let mut success_count = 0;
+    let mut failures: BTreeMap<ErrorKind, usize> = BTreeMap::new();
+    let mut processed = 0;
+
+    progress.set(0, total);
+    for item in items {
+        if progress.is_cancelled() {
+            break;
+        }
+        let result = backend.restore(vec![item.clone()]).map_err(|e| match &e {
+            trash::Error::RestoreCollision { path, .. } => ItemError::new(
+                ErrorKind::NameCollision,
+                trf("error.already_exists", &[&path.display()]),
+            ),
+            _ => ItemError::from(&e),
+        });
+        match &result {
+            Ok(()) => {
+                success_count += 1;
                 table.add_row(vec![
+                    Cell::new(tr("status.ok")),
                     Cell::new(&item.name),
-                    Cell::new(&formatted_time_deleted),
+                    Cell::new(item.original_parent.display()),
+                    Cell::new(""),
+                    Cell::new(""),
                 ]);
             }
-            None => {
-                console_app.add_to_buffer("Erreur lors de la conversion de la date.");
-                return;
+            Err(error) => {
+                warn!(item = %item.name, error = %error.message, "restauration impossible");
+                *failures.entry(error.kind).or_default() += 1;
+                table.add_row(vec![
+                    Cell::new(tr("status.oops")),
+                    Cell::new(&item.name),
+                    Cell::new(item.original_parent.display()),
+                    Cell::new(error.kind.label()),
+                    Cell::new(&error.message),
+                ]);
             }
+        }
+        report.items.push(match result {
+            Ok(()) => ItemRecord::new(&item, ItemStatus::Restored, None),
+            Err(error) => ItemRecord::new(&item, ItemStatus::Failed, Some(error)),
+        });
+        processed += 1;
+        progress.set(processed, total);
+    }
+    console_app.info(
+        Category::Restore,
+        &format!("\n\n{}\n\n", tr("restore.title")),
+    );
+    console_app.table(Category::Restore, &table);
+
+    let stats_table = statistics_table(success_count, &failures, total - processed);
+    console_app.info(Category::Restore, &format!("\n{}\n", tr("restore.stats")));
+    console_app.table(Category::Restore, &stats_table);
+
+    if processed < total {
+        console_app.warn(
+            Category::Restore,
+            &trf("restore.cancelled", &[&(total - processed)]),
+        );
+        report.with_outcome(Outcome::Cancelled)
+    } else if !failures.is_empty() {
+        report.with_outcome(Outcome::PartialFailure)
+    } else {
+        report
+    }
+}
+
+/// Tableau des statistiques: succès, échecs regroupés par cause et
+/// éléments non traités après une annulation.
+fn statistics_table(
+    success_count: usize,
+    failures: &BTreeMap<ErrorKind, usize>,
+    not_processed: usize,
+) -> Table {
+    let mut stats_table = Table::new();
+    stats_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new(tr("column.status")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.count")).set_alignment(CellAlignment::Left),
+    ]);
+
+    stats_table.add_row(vec![
+        Cell::new(tr("stats.success")),
+        Cell::new(success_count.to_string()),
+    ]);
+
+    if failures.is_empty() {
+        stats_table.add_row(vec![Cell::new(tr("stats.failure")), Cell::new("0")]);
+    }
+    for (kind, count) in failures {
+        stats_table.add_row(vec![
+            Cell::new(trf("stats.failure_kind", &[&kind.label()])),
+            Cell::new(count.to_string()),
+        ]);
+    }
+
+    if not_processed > 0 {
+        stats_table.add_row(vec![
+            Cell::new(tr("stats.not_processed")),
+            Cell::new(not_processed.to_string()),
+        ]);
+    }
+    stats_table
+}
+
+//___________________FUNCTION BUTTON SUPPRIMER_DEFINITIVEMENT__________________
+
+#[instrument(name = "purge", skip_all)]
+pub(crate) fn supprimer_definitivement(
+    console_app: &mut ConsoleApp,
+    config_app: &mut ConfigApp,
+    backend: &dyn TrashBackend,
+    quarantine: Option<&Quarantine>,
+    audit_log: Option<&AuditLog>,
+    progress: &Progress,
+) -> Report {
+    // En ligne de commande, aucun passage du planificateur ne s'en charge.
+    supprimer_quarantaine_expiree(console_app, config_app, quarantine, audit_log);
+    let elements_to_process =
+        match get_elements_to_process(console_app, config_app, backend, progress) {
+            Ok(selection) => selection.candidates,
+            Err(_) => return Report::new(ReportKind::Purge).with_outcome(Outcome::ListingError),
         };
+    let report = purger(
+        console_app,
+        config_app,
+        backend,
+        quarantine,
+        elements_to_process,
+        None,
+        progress,
+    );
+    journaliser(
+        console_app,
+        config_app,
+        audit_log,
+        PurgeTrigger::CommandLine,
+        &report,
+    );
+    report
+}
+
+/// Ajoute l'exécution au journal d'audit des suppressions.
+fn journaliser(
+    console_app: &mut ConsoleApp,
+    config_app: &ConfigApp,
+    audit_log: Option<&AuditLog>,
+    trigger: PurgeTrigger,
+    report: &Report,
+) {
+    let Some(audit_log) = audit_log else {
+        console_app.error(
+            Category::Purge,
+            &trf("audit.write_error", &[&tr("data_dir.missing")]),
+        );
+        return;
+    };
+    let entry = AuditEntry::new(trigger, config_app, report);
+    if let Err(e) = audit_log.append(&entry) {
+        console_app.error(Category::Purge, &trf("audit.write_error", &[&e]));
     }
+}
 
-    console_app.add_to_buffer("\n\nANALYSE\n\n");
+//______________________FUNCTION SUPPRESSION_PLANIFIEE________________________
 
-    if !elements_to_process.is_empty() {
-        console_app.add_to_buffer(&table.to_string());
+/// Applique la politique configurée sans confirmation, sauf si la sélection
+/// dépasse les limites de sécurité de la planification.
+#[instrument(name = "scheduled_run", skip_all)]
+pub(crate) fn suppression_planifiee(
+    console_app: &mut ConsoleApp,
+    config_app: &mut ConfigApp,
+    backend: &dyn TrashBackend,
+    quarantine: Option<&Quarantine>,
+    audit_log: Option<&AuditLog>,
+    progress: &Progress,
+) -> (ScheduledRun, Option<Report>) {
+    let mut run = ScheduledRun {
+        time: Local::now().timestamp(),
+        status: RunStatus::Success,
+        items: 0,
+        size: 0,
+        detail: String::new(),
+    };
+    console_app.info(
+        Category::Schedule,
+        &format!("\n\n{}\n", tr("schedule.console_title")),
+    );
+
+    let candidates = match get_elements_to_process(console_app, config_app, backend, progress) {
+        Ok(selection) => selection.candidates,
+        Err(e) => {
+            run.status = RunStatus::Failed;
+            run.detail = e.to_string();
+            return (run, None);
+        }
+    };
+    let total_size: u64 = candidates.iter().filter_map(|c| c.size).sum();
+    if let Some(reason) = config_app
+        .schedule
+        .exceeds_limits(candidates.len(), total_size)
+    {
+        warn!(reason = %reason, "exécution planifiée ignorée");
+        console_app.warn(Category::Schedule, &trf("schedule.skipped", &[&reason]));
+        run.status = RunStatus::Skipped;
+        run.detail = reason;
+        return (run, None);
+    }
+    if candidates.is_empty() {
+        console_app.info(Category::Schedule, &format!("{}\n", tr("purge.nothing")));
+        return (run, None);
+    }
+
+    let report = purger(
+        console_app,
+        config_app,
+        backend,
+        quarantine,
+        candidates,
+        None,
+        progress,
+    );
+    journaliser(
+        console_app,
+        config_app,
+        audit_log,
+        PurgeTrigger::Scheduled,
+        &report,
+    );
+    let purged = report
+        .items
+        .iter()
+        .filter(|record| matches!(record.status, ItemStatus::Purged | ItemStatus::Quarantined));
+    run.items = purged.clone().count();
+    run.size = purged.filter_map(|record| record.size).sum();
+    let failed = report.items.len() - run.items;
+    if failed > 0 || report.outcome != Outcome::Success {
+        run.status = RunStatus::PartialFailure;
+        run.detail = trf("schedule.not_purged", &[&failed]);
+    }
+    (run, Some(report))
+}
+
+//_______________________FUNCTION SUPPRIMER_SELECTION__________________________
+
+/// Supprime uniquement les éléments cochés après une analyse, en vérifiant
+/// juste avant qu'ils sont toujours dans la corbeille avec le même identifiant.
+#[instrument(name = "purge", skip_all, fields(items = elements_to_process.len()))]
+pub(crate) fn supprimer_selection(
+    console_app: &mut ConsoleApp,
+    config_app: &ConfigApp,
+    backend: &dyn TrashBackend,
+    quarantine: Option<&Quarantine>,
+    audit_log: Option<&AuditLog>,
+    elements_to_process: Vec<Candidate>,
+    progress: &Progress,
+) -> Report {
+    let still_in_trash: HashSet<OsString> = match backend.list() {
+        Ok(items) => items.into_iter().map(|item| item.id).collect(),
+        Err(e) => {
+            console_app.error(Category::Trash, &trf("trash.list_error", &[&e]));
+            return Report::new(ReportKind::Purge).with_outcome(Outcome::ListingError);
+        }
+    };
+    let report = purger(
+        console_app,
+        config_app,
+        backend,
+        quarantine,
+        elements_to_process,
+        Some(&still_in_trash),
+        progress,
+    );
+    journaliser(
+        console_app,
+        config_app,
+        audit_log,
+        PurgeTrigger::Interface,
+        &report,
+    );
+    report
+}
+
+/// Supprime définitivement les éléments, ou les place en quarantaine si elle
+/// est activée.
+fn purger(
+    console_app: &mut ConsoleApp,
+    config_app: &ConfigApp,
+    backend: &dyn TrashBackend,
+    quarantine: Option<&Quarantine>,
+    elements_to_process: Vec<Candidate>,
+    still_in_trash: Option<&HashSet<OsString>>,
+    progress: &Progress,
+) -> Report {
+    let mut report = Report::new(ReportKind::Purge);
+    let total = elements_to_process.len();
+
+    // Créez une nouvelle table avec le style UTF8_FULL
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new(tr("column.status")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.file_name")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.trashed_date")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.cause")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.detail")).set_alignment(CellAlignment::Left),
+    ]);
+
+    let mut success_count = 0;
+    let mut failures: BTreeMap<ErrorKind, usize> = BTreeMap::new();
+    let mut processed = 0;
+
+    let is_in_trash =
+        |item: &trash::TrashItem| still_in_trash.map_or(true, |ids| ids.contains(&item.id));
+    let to_purge: Vec<Candidate> = elements_to_process
+        .iter()
+        .filter(|candidate| is_in_trash(&candidate.item))
+        .cloned()
+        .collect();
+    let (success_status, title) = if config_app.quarantine_enabled {
+        (ItemStatus::Quarantined, tr("purge.quarantine_title"))
+    } else {
+        (ItemStatus::Purged, tr("purge.title"))
+    };
+    let purge_results: Vec<Result<(), ItemError>> = if config_app.quarantine_enabled {
+        match quarantine {
+            Some(quarantine) => quarantine.admit(backend, &to_purge, progress),
+            // Sans quarantaine, les éléments restent dans la corbeille.
+            None => to_purge
+                .iter()
+                .map(|_| {
+                    Err(ItemError::new(
+                        ErrorKind::Other,
+                        tr("quarantine.unavailable"),
+                    ))
+                })
+                .collect(),
+        }
+    } else {
+        let items: Vec<trash::TrashItem> = to_purge.iter().map(|c| c.item.clone()).collect();
+        purge_in_batches(backend, &items, progress)
+            .into_iter()
+            .map(|result| result.map_err(|e| ItemError::from(&e)))
+            .collect()
+    };
+    let cancelled = purge_results.len() < to_purge.len();
+    let mut purge_results = purge_results.into_iter();
+
+    for candidate in &elements_to_process {
+        // Opération annulée: les éléments restants ne sont pas traités.
+        if cancelled && purge_results.len() == 0 {
+            break;
+        }
+        let item = &candidate.item;
+        let result = if is_in_trash(item) {
+            purge_results
+                .next()
+                .expect("un résultat par élément non annulé")
+        } else {
+            Err(ItemError::new(
+                ErrorKind::NotFound,
+                tr("error.not_in_trash"),
+            ))
+        };
+        let (status_logo, cause, detail) = match &result {
+            Ok(()) => {
+                success_count += 1;
+                (tr("status.ok"), "", "")
+            }
+            Err(error) => {
+                *failures.entry(error.kind).or_default() += 1;
+                (
+                    tr("status.oops"),
+                    error.kind.label(),
+                    error.message.as_str(),
+                )
+            }
+        };
+        table.add_row(vec![
+            Cell::new(status_logo),
+            Cell::new(&item.name),
+            Cell::new(format_date(item.time_deleted)),
+            Cell::new(cause),
+            Cell::new(detail),
+        ]);
+        report.items.push(
+            match result {
+                Ok(()) => ItemRecord::new(item, success_status, None),
+                Err(error) => ItemRecord::new(item, ItemStatus::Failed, Some(error)),
+            }
+            .with_candidate(candidate),
+        );
+        processed += 1;
+    }
+    console_app.info(Category::Purge, &format!("\n\n{}\n\n", title));
+    console_app.table(Category::Purge, &table);
+
+    let stats_table = statistics_table(success_count, &failures, total - processed);
+    console_app.info(Category::Purge, &format!("\n{}\n", tr("purge.stats")));
+    console_app.table(Category::Purge, &stats_table);
+
+    if cancelled {
+        console_app.warn(
+            Category::Purge,
+            &trf("purge.cancelled", &[&(total - processed)]),
+        );
+        report.with_outcome(Outcome::Cancelled)
+    } else if !failures.is_empty() {
+        report.with_outcome(Outcome::PartialFailure)
+    } else {
+        report
+    }
+}
+
+//_____________________________FUNCTION QUARANTINE_____________________________
+
+/// Supprime définitivement des éléments de la quarantaine et l'enregistre
+/// dans le journal d'audit.
+fn supprimer_de_quarantaine(
+    console_app: &mut ConsoleApp,
+    config_app: &ConfigApp,
+    quarantine: &Quarantine,
+    audit_log: Option<&AuditLog>,
+    items: &[QuarantinedItem],
+    trigger: PurgeTrigger,
+) -> Report {
+    let mut report = Report::new(ReportKind::Purge);
+    for quarantined in items {
+        let item = quarantined.to_trash_item();
+        let mut record = match quarantine.purge(quarantined) {
+            Ok(()) => ItemRecord::new(&item, ItemStatus::Purged, None),
+            Err(error) => {
+                console_app.error(
+                    Category::Quarantine,
+                    &trf(
+                        "quarantine.purge_error",
+                        &[&quarantined.name, &error.message],
+                    ),
+                );
+                report.outcome = Outcome::PartialFailure;
+                ItemRecord::new(&item, ItemStatus::Failed, Some(error))
+            }
+        };
+        record.size = quarantined.size;
+        report.items.push(record);
+    }
+    journaliser(console_app, config_app, audit_log, trigger, &report);
+    report
+}
+
+/// Supprime définitivement les éléments dont la quarantaine est terminée.
+pub(crate) fn supprimer_quarantaine_expiree(
+    console_app: &mut ConsoleApp,
+    config_app: &ConfigApp,
+    quarantine: Option<&Quarantine>,
+    audit_log: Option<&AuditLog>,
+) {
+    // Sans dossier de données, aucune quarantaine n'a pu être remplie.
+    let Some(quarantine) = quarantine else {
+        return;
+    };
+    let expired = match quarantine.expired(config_app.quarantine_days) {
+        Ok(expired) => expired,
+        Err(e) => {
+            console_app.error(Category::Quarantine, &trf("quarantine.read_error", &[&e]));
+            return;
+        }
+    };
+    if expired.is_empty() {
+        return;
+    }
+    let report = supprimer_de_quarantaine(
+        console_app,
+        config_app,
+        quarantine,
+        audit_log,
+        &expired,
+        PurgeTrigger::QuarantineExpiry,
+    );
+    let purged = report
+        .items
+        .iter()
+        .filter(|record| record.status == ItemStatus::Purged)
+        .count();
+    console_app.info(
+        Category::Quarantine,
+        &trf("quarantine.expired_purged", &[&purged]),
+    );
+}
+
+/// Fenêtre de la quarantaine: restauration ou suppression immédiate des éléments.
+/// Retourne `true` lorsque la suppression des éléments expirés est demandée,
+/// elle s'exécute en arrière-plan.
+fn quarantine_window(
+    ctx: &egui::Context,
+    quarantine_dialog: &mut QuarantineDialog,
+    console_app: &mut ConsoleApp,
+    config_app: &ConfigApp,
+    quarantine: Option<&Quarantine>,
+    audit_log: Option<&AuditLog>,
+    busy: bool,
+) -> bool {
+    let mut open = quarantine_dialog.open;
+    let mut action = None;
+    let mut confirm_purge = quarantine_dialog.confirm_purge;
+    egui::Window::new(tr("quarantine.title"))
+        .open(&mut open)
+        .default_width(620.0)
+        .show(ctx, |ui| {
+            ui.label(trf("quarantine.hint", &[&config_app.quarantine_days]));
+            ui.horizontal(|ui| {
+                if ui.button(tr("quarantine.refresh")).clicked() {
+                    action = Some(QuarantineAction::Reload);
+                }
+                if ui
+                    .add_enabled(!busy, egui::Button::new(tr("quarantine.purge_expired")))
+                    .clicked()
+                {
+                    action = Some(QuarantineAction::PurgeExpired);
+                }
+            });
+            if let Some(error) = &quarantine_dialog.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    if quarantine_dialog.items.is_empty() {
+                        ui.label(tr("quarantine.empty"));
+                    }
+                    egui::Grid::new("quarantine_items")
+                        .num_columns(6)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for (index, item) in quarantine_dialog.items.iter().enumerate() {
+                                ui.label(&item.name);
+                                ui.label(item.original_parent.display().to_string());
+                                ui.label(format_size(item.size));
+                                ui.label(trf(
+                                    "quarantine.expires",
+                                    &[&format_date(item.expires_at(config_app.quarantine_days))],
+                                ));
+                                if ui.small_button(tr("quarantine.restore")).clicked() {
+                                    action = Some(QuarantineAction::Restore(index));
+                                }
+                                // Suppression définitive: un second clic la confirme.
+                                if quarantine_dialog.confirm_purge == Some(index) {
+                                    if ui.small_button(tr("quarantine.confirm_purge")).clicked() {
+                                        action = Some(QuarantineAction::Purge(index));
+                                    }
+                                } else if ui.small_button(tr("quarantine.purge")).clicked() {
+                                    confirm_purge = Some(index);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+    quarantine_dialog.open = open;
+    quarantine_dialog.confirm_purge = confirm_purge;
+
+    let Some(quarantine) = quarantine else {
+        return false;
+    };
+    match action {
+        Some(QuarantineAction::Restore(index)) => {
+            let item = &quarantine_dialog.items[index];
+            match quarantine.restore(item) {
+                Ok(()) => console_app.info(
+                    Category::Quarantine,
+                    &trf("quarantine.restored", &[&item.original_path().display()]),
+                ),
+                Err(error) => console_app.error(
+                    Category::Quarantine,
+                    &trf("quarantine.restore_error", &[&item.name, &error.message]),
+                ),
+            }
+        }
+        Some(QuarantineAction::Purge(index)) => {
+            let item = quarantine_dialog.items[index].clone();
+            supprimer_de_quarantaine(
+                console_app,
+                config_app,
+                quarantine,
+                audit_log,
+                &[item],
+                PurgeTrigger::Interface,
+            );
+        }
+        Some(QuarantineAction::PurgeExpired) => return true,
+        Some(QuarantineAction::Reload) | None => {}
+    }
+    if action.is_some() {
+        quarantine_dialog.reload(Some(quarantine));
+    }
+    false
+}
+
+//__________________________FUNCTION BUTTON ANALYSER___________________________
+#[instrument(name = "analysis", skip_all)]
+pub(crate) fn analyser(
+    console_app: &mut ConsoleApp,
+    config_app: &mut ConfigApp,
+    backend: &dyn TrashBackend,
+    progress: &Progress,
+) -> Report {
+    let mut report = Report::new(ReportKind::Analysis);
+    let Selection {
+        candidates: elements_to_process,
+        protected,
+    } = match get_elements_to_process(console_app, config_app, backend, progress) {
+        Ok(selection) => selection,
+        Err(_) => return report.with_outcome(Outcome::ListingError),
+    };
+
+    for candidate in &elements_to_process {
+        report.items.push(
+            ItemRecord::new(&candidate.item, ItemStatus::Selected, None).with_candidate(candidate),
+        );
+    }
+    for (item, rule) in &protected {
+        report
+            .items
+            .push(ItemRecord::new(item, ItemStatus::Protected, None).with_protection(rule));
+    }
+
+    console_app.info(
+        Category::Analysis,
+        &format!("\n\n{}\n\n", tr("analysis.title")),
+    );
 
+    if !elements_to_process.is_empty() {
         let total_items = elements_to_process.len();
-        console_app.add_to_buffer(&format!("\nTotal d'éléments à traiter : {}\n", total_items));
+        console_app.info(Category::Analysis, &trf("analysis.total", &[&total_items]));
+
+        let total_size: u64 = elements_to_process.iter().filter_map(|c| c.size).sum();
+        console_app.info(
+            Category::Analysis,
+            &trf("analysis.freed", &[&format_size(Some(total_size))]),
+        );
     } else {
-        console_app.add_to_buffer("Il n'y a aucun élément à supprimer");
+        console_app.info(Category::Analysis, tr("purge.nothing"));
+    }
+
+    if !protected.is_empty() {
+        console_app.info(
+            Category::Analysis,
+            &trf("analysis.protected", &[&protected.len()]),
+        );
+    }
+
+    report
+}
+
+//_________________________FUNCTION COMPARER_PROFILS__________________________
+
+/// Analyse avec la politique en vigueur puis affiche les éléments que `other`
+/// sélectionnerait différemment. Le rapport retourné est celui de la politique en vigueur.
+pub(crate) fn comparer_profils(
+    console_app: &mut ConsoleApp,
+    config_app: &mut ConfigApp,
+    other: &Profile,
+    backend: &dyn TrashBackend,
+    progress: &Progress,
+) -> Report {
+    let report = analyser(console_app, config_app, backend, progress);
+    if report.outcome == Outcome::ListingError {
+        return report;
+    }
+    let mut other_config = config_app.clone();
+    other.apply(&mut other_config);
+    let other_selection =
+        match get_elements_to_process(console_app, &mut other_config, backend, progress) {
+            Ok(selection) => selection,
+            Err(_) => return report.with_outcome(Outcome::ListingError),
+        };
+
+    let current: Vec<(&trash::TrashItem, Option<u64>)> = report
+        .items
+        .iter()
+        .filter(|record| record.status == ItemStatus::Selected)
+        .map(|record| (&record.item, record.size))
+        .collect();
+    let current_ids: HashSet<&OsString> = current.iter().map(|(item, _)| &item.id).collect();
+    let other_ids: HashSet<&OsString> = other_selection
+        .candidates
+        .iter()
+        .map(|candidate| &candidate.item.id)
+        .collect();
+    let only_current: Vec<(&trash::TrashItem, Option<u64>)> = current
+        .iter()
+        .copied()
+        .filter(|(item, _)| !other_ids.contains(&item.id))
+        .collect();
+    let only_other: Vec<(&trash::TrashItem, Option<u64>)> = other_selection
+        .candidates
+        .iter()
+        .filter(|candidate| !current_ids.contains(&candidate.item.id))
+        .map(|candidate| (&candidate.item, candidate.size))
+        .collect();
+
+    let current_name = config_app.policy_name();
+    console_app.info(
+        Category::Analysis,
+        &format!(
+            "\n\n{}\n\n",
+            trf("comparison.title", &[&current_name, &other.name])
+        ),
+    );
+    let total_size = |items: &[(&trash::TrashItem, Option<u64>)]| -> String {
+        format_size(Some(items.iter().filter_map(|(_, size)| *size).sum()))
+    };
+    let other_items: Vec<(&trash::TrashItem, Option<u64>)> = other_selection
+        .candidates
+        .iter()
+        .map(|candidate| (&candidate.item, candidate.size))
+        .collect();
+
+    let mut summary = Table::new();
+    summary.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new(tr("column.profile")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.count")).set_alignment(CellAlignment::Right),
+        Cell::new(tr("column.size")).set_alignment(CellAlignment::Right),
+    ]);
+    let only_current_label = trf("comparison.only", &[&current_name]);
+    let only_other_label = trf("comparison.only", &[&other.name]);
+    for (label, items) in [
+        (current_name, &current),
+        (other.name.as_str(), &other_items),
+        (only_current_label.as_str(), &only_current),
+        (only_other_label.as_str(), &only_other),
+    ] {
+        summary.add_row(vec![
+            Cell::new(label),
+            Cell::new(items.len()).set_alignment(CellAlignment::Right),
+            Cell::new(total_size(items)).set_alignment(CellAlignment::Right),
+        ]);
+    }
+    console_app.table(Category::Analysis, &summary);
+
+    if only_current.is_empty() && only_other.is_empty() {
+        console_app.info(
+            Category::Analysis,
+            &format!("\n{}\n", tr("comparison.identical")),
+        );
+        return report;
+    }
+    let mut differences = Table::new();
+    differences.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new(tr("column.item_name")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.deletion_date")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.size")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.selected_by")).set_alignment(CellAlignment::Left),
+    ]);
+    for (items, name) in [(&only_current, current_name), (&only_other, &other.name)] {
+        for (item, size) in items.iter() {
+            differences.add_row(vec![
+                Cell::new(&item.name),
+                Cell::new(format_date(item.time_deleted)),
+                Cell::new(format_size(*size)),
+                Cell::new(name),
+            ]);
+        }
+    }
+    console_app.info(
+        Category::Analysis,
+        &format!("\n{}\n", tr("comparison.differences")),
+    );
+    console_app.table(Category::Analysis, &differences);
+
+    report
+}
+
+/// Tableaux texte d'une analyse, pour la ligne de commande.
+pub(crate) fn afficher_analyse(console_app: &mut ConsoleApp, report: &Report) {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new(tr("column.item_name")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.deletion_date")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.size")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.rule")).set_alignment(CellAlignment::Left),
+    ]);
+
+    let mut protected_table = Table::new();
+    protected_table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new(tr("column.item_name")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.original_folder")).set_alignment(CellAlignment::Left),
+        Cell::new(tr("column.protected_by")).set_alignment(CellAlignment::Left),
+    ]);
+
+    for record in &report.items {
+        match record.status {
+            ItemStatus::Protected => {
+                protected_table.add_row(vec![
+                    Cell::new(&record.name),
+                    Cell::new(record.item.original_parent.display()),
+                    Cell::new(record.protected_by.as_deref().unwrap_or_default()),
+                ]);
+            }
+            _ => {
+                table.add_row(vec![
+                    Cell::new(&record.name),
+                    Cell::new(format_date(record.item.time_deleted)),
+                    Cell::new(format_size(record.size)),
+                    Cell::new(record.rule.map(|rule| rule.label()).unwrap_or_default()),
+                ]);
+            }
+        }
+    }
+
+    if table.row_iter().len() > 0 {
+        console_app.table(Category::Analysis, &table);
     }
+    if protected_table.row_iter().len() > 0 {
+        console_app.info(
+            Category::Analysis,
+            &format!("\n{}\n", tr("analysis.protected_title")),
+        );
+        console_app.table(Category::Analysis, &protected_table);
+    }
+}
+
+//__________________________FUNCTION LISTER_CORBEILLE_________________________
+
+/// Lit le contenu de la corbeille, pour la sélection comme pour le tableau de bord.
+fn lister_corbeille(
+    console_app: &mut ConsoleApp,
+    backend: &dyn TrashBackend,
+) -> Result<Vec<trash::TrashItem>, trash::Error> {
+    backend.list().map_err(|e| {
+        error!(error = %e, "lecture de la corbeille impossible");
+        console_app.error(Category::Trash, &trf("trash.list_error", &[&e]));
+        e
+    })
+}
+
+//_________________________FUNCTION RELEVER_CORBEILLE__________________________
+
+/// Relève le contenu de la corbeille et la taille de chaque élément pour le
+/// tableau de bord.
+#[instrument(name = "dashboard", skip_all)]
+fn relever_corbeille(
+    console_app: &mut ConsoleApp,
+    backend: &dyn TrashBackend,
+    progress: &Progress,
+) -> Option<TrashSnapshot> {
+    let trash_items = lister_corbeille(console_app, backend).ok()?;
+    let total = trash_items.len();
+    progress.set(0, total);
+    let items = trash_items
+        .into_iter()
+        .enumerate()
+        .map(|(index, item)| {
+            progress.set(index + 1, total);
+            let size = item_size(backend, &item);
+            (item, size)
+        })
+        .collect();
+    debug!(items = total, "relevé terminé");
+    Some(TrashSnapshot {
+        items,
+        taken_at: Local::now().timestamp(),
+    })
 }
 
 //______________________FUNCTION GET_ELEMENTS_TO_PROCESS_______________________
+#[instrument(name = "list", skip_all)]
 fn get_elements_to_process(
     console_app: &mut ConsoleApp,
     config_app: &mut ConfigApp,
-) -> Vec<trash::TrashItem> {
-    let now = Local::now().naive_local();
-    let duration = config_app.time_threshold as i64;
-    let threshold = Duration::days(duration);
-    let trash_items = match list() {
-        Ok(items) => items,
-        Err(e) => {
-            console_app.add_to_buffer(&format!(
-                "**** Erreur lors de la récupération des éléments de la corbeille: {} ****\n",
-                e
+    backend: &dyn TrashBackend,
+    progress: &Progress,
+) -> Result<Selection, trash::Error> {
+    let cutoff = config_app.time_threshold.cutoff(Local::now());
+    let trash_items = lister_corbeille(console_app, backend)?;
+
+    debug!(items = trash_items.len(), cutoff = ?cutoff, "corbeille lue");
+
+    let rules = &config_app.protection_rules;
+    let mut selected = Vec::new();
+    let mut protected = Vec::new();
+    let mut kept = Vec::new();
+    let total = trash_items.len();
+    progress.set(0, total);
+    for (index, item) in trash_items.into_iter().enumerate() {
+        progress.set(index + 1, total);
+        let time_deleted = match DateTime::from_timestamp(item.time_deleted, 0) {
+            Some(time) => time,
+            None => {
+                warn!(item = %item.name, time_deleted = item.time_deleted, "horodatage invalide");
+                console_app.error(Category::Trash, tr("error.timestamp"));
+                continue;
+            }
+        };
+        if cutoff.map_or(false, |cutoff| time_deleted < cutoff) {
+            if let Some(rule) = protecting_rule(rules, &item) {
+                protected.push((item, rule.clone()));
+                continue;
+            }
+            selected.push(Candidate {
+                rule: SelectionRule::Age,
+                size: item_size(backend, &item),
+                item,
+            });
+        } else {
+            kept.push(item);
+        }
+    }
+
+    // Taille maximale: on retire les plus anciens éléments restants jusqu'à
+    // repasser sous le quota. Les éléments protégés restent dans la corbeille
+    // et comptent donc dans sa taille. La taille des dossiers est inconnue:
+    // ils comptent pour zéro et leur suppression ne réduit pas la taille.
+    if config_app.size_quota_enabled {
+        let quota = (config_app.size_quota_gb * consts::BYTES_PER_GB) as u64;
+        let protected_size: u64 = protected
+            .iter()
+            .filter_map(|(item, _)| item_size(backend, item))
+            .sum();
+
+        let mut kept: Vec<(trash::TrashItem, Option<u64>)> = kept
+            .into_iter()
+            .map(|item| {
+                let size = item_size(backend, &item);
+                (item, size)
+            })
+            .collect();
+        kept.sort_by_key(|(item, _)| item.time_deleted);
+
+        let unknown = kept.iter().filter(|(_, size)| size.is_none()).count();
+        let mut total_size: u64 =
+            protected_size + kept.iter().filter_map(|(_, size)| *size).sum::<u64>();
+        debug!(total_size, unknown, quota, "taille de la corbeille");
+        for (item, size) in kept {
+            if total_size <= quota {
+                break;
+            }
+            if let Some(rule) = protecting_rule(rules, &item) {
+                protected.push((item, rule.clone()));
+                continue;
+            }
+            total_size -= size.unwrap_or(0);
+            selected.push(Candidate {
+                item,
+                rule: SelectionRule::SizeQuota,
+                size,
+            });
+        }
+    }
+
+    debug!(
+        selected = selected.len(),
+        protected = protected.len(),
+        "sélection terminée"
+    );
+    Ok(Selection {
+        candidates: selected,
+        protected,
+    })
+}
+
+/// Taille en octets d'un élément, inconnue pour les dossiers.
+fn item_size(backend: &dyn TrashBackend, item: &trash::TrashItem) -> Option<u64> {
+    backend
+        .metadata(item)
+        .ok()
+        .and_then(|metadata| metadata.size.size())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::backend::MemoryTrash;
+    use crate::report::ErrorKind;
+
+    /// Politique de test: 30 jours de rétention, les `.psd` protégés.
+    fn config() -> ConfigApp {
+        ConfigApp {
+            time_threshold: Retention::days(30),
+            protection_rules: vec![ProtectionRule::Extension("psd".to_owned())],
+            ..Default::default()
+        }
+    }
+
+    /// Dossier de données propre au test, supprimé à la fin: la quarantaine
+    /// et le journal d'audit du poste ne sont jamais touchés.
+    struct DataDir(std::path::PathBuf);
+
+    impl DataDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "windows_trash_manager-app-{}-{}",
+                std::process::id(),
+                name
             ));
-            return vec![];
+            let _ = std::fs::remove_dir_all(&path);
+            Self(path)
         }
-    };
 
-    trash_items
-        .into_iter()
-        .filter(|item| {
-            let time_deleted = match NaiveDateTime::from_timestamp_opt(item.time_deleted, 0) {
-                Some(time) => time,
-                None => {
-                    console_app
-                        .add_to_buffer("**** Erreur lors de la conversion de l'horodatage ****\n");
-                    return false;
-                }
-            };
-            let time_deleted_local = Local.from_utc_datetime(&time_deleted);
-            now.signed_duration_since(time_deleted_local.naive_local()) > threshold
-        })
-        .collect()
+        fn quarantine(&self) -> Quarantine {
+            Quarantine::new(self.0.join("quarantine"))
+        }
+
+        fn audit_log(&self) -> AuditLog {
+            AuditLog::new(self.0.join("audit.jsonl"))
+        }
+    }
+
+    impl Drop for DataDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn seeded() -> MemoryTrash {
+        let trash = MemoryTrash::new();
+        trash.seed("ancien.txt", "/tmp", Duration::days(60), 100);
+        trash.seed("récent.txt", "/tmp", Duration::days(2), 200);
+        trash.seed("maquette.psd", "/tmp", Duration::days(90), 300);
+        trash.seed("vieux.log", "/var/log", Duration::days(45), 400);
+        trash
+    }
+
+    fn names(items: impl IntoIterator<Item = String>) -> Vec<String> {
+        let mut names: Vec<String> = items.into_iter().collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn selection_keeps_recent_and_protected_items() {
+        let trash = seeded();
+        let selection = get_elements_to_process(
+            &mut ConsoleApp::default(),
+            &mut config(),
+            &trash,
+            &Progress::default(),
+        )
+        .unwrap();
+
+        let selected = selection.candidates.iter().map(|c| c.item.name.clone());
+        assert_eq!(names(selected), ["ancien.txt", "vieux.log"]);
+        assert!(selection
+            .candidates
+            .iter()
+            .all(|c| c.rule == SelectionRule::Age));
+        assert_eq!(selection.protected.len(), 1);
+        assert_eq!(selection.protected[0].0.name, "maquette.psd");
+    }
+
+    #[test]
+    fn analysis_reports_without_deleting() {
+        let trash = seeded();
+        let report = analyser(
+            &mut ConsoleApp::default(),
+            &mut config(),
+            &trash,
+            &Progress::default(),
+        );
+
+        assert_eq!(report.outcome, Outcome::Success);
+        let with_status = |status| {
+            names(
+                report
+                    .items
+                    .iter()
+                    .filter(|record| record.status == status)
+                    .map(|record| record.name.clone()),
+            )
+        };
+        assert_eq!(
+            with_status(ItemStatus::Selected),
+            ["ancien.txt", "vieux.log"]
+        );
+        assert_eq!(with_status(ItemStatus::Protected), ["maquette.psd"]);
+        assert_eq!(trash.len(), 4);
+    }
+
+    #[test]
+    fn purge_deletes_the_selection_only() {
+        let trash = seeded();
+        let mut console_app = ConsoleApp::default();
+        let config_app = config();
+        let candidates = get_elements_to_process(
+            &mut console_app,
+            &mut config_app.clone(),
+            &trash,
+            &Progress::default(),
+        )
+        .unwrap()
+        .candidates;
+        let data = DataDir::new("purge");
+        let report = purger(
+            &mut console_app,
+            &config_app,
+            &trash,
+            Some(&data.quarantine()),
+            candidates,
+            None,
+            &Progress::default(),
+        );
+
+        assert_eq!(report.outcome, Outcome::Success);
+        assert_eq!(report.items.len(), 2);
+        assert!(report
+            .items
+            .iter()
+            .all(|record| record.status == ItemStatus::Purged));
+        let remaining = trash.list().unwrap().into_iter().map(|item| item.name);
+        assert_eq!(names(remaining), ["maquette.psd", "récent.txt"]);
+    }
+
+    #[test]
+    fn purge_skips_items_no_longer_in_the_trash() {
+        let trash = seeded();
+        let mut console_app = ConsoleApp::default();
+        let config_app = config();
+        let candidates = get_elements_to_process(
+            &mut console_app,
+            &mut config_app.clone(),
+            &trash,
+            &Progress::default(),
+        )
+        .unwrap()
+        .candidates;
+        // Un des éléments a été restauré entre l'analyse et la suppression.
+        let gone = candidates[0].item.clone();
+        trash.restore(vec![gone.clone()]).unwrap();
+        let still_in_trash: HashSet<OsString> = trash
+            .list()
+            .unwrap()
+            .into_iter()
+            .map(|item| item.id)
+            .collect();
+
+        let data = DataDir::new("restored");
+        let report = purger(
+            &mut console_app,
+            &config_app,
+            &trash,
+            Some(&data.quarantine()),
+            candidates,
+            Some(&still_in_trash),
+            &Progress::default(),
+        );
+
+        assert_eq!(report.outcome, Outcome::PartialFailure);
+        let failed: Vec<&ItemRecord> = report
+            .items
+            .iter()
+            .filter(|record| record.status == ItemStatus::Failed)
+            .collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].name, gone.name);
+        assert_eq!(failed[0].error_kind, Some(ErrorKind::NotFound));
+        assert_eq!(trash.len(), 2);
+    }
+
+    #[test]
+    fn quota_selects_the_oldest_unprotected_items_first() {
+        let gb = consts::BYTES_PER_GB as u64;
+        let trash = MemoryTrash::new();
+        trash.seed("récent-2.txt", "/tmp", Duration::days(2), gb);
+        trash.seed("récent-5.txt", "/tmp", Duration::days(5), gb);
+        trash.seed("récent-1.txt", "/tmp", Duration::days(1), gb);
+        trash.seed("récent-3.psd", "/tmp", Duration::days(3), gb);
+        trash.seed("récent-4.txt", "/tmp", Duration::days(4), gb);
+        // Déjà sélectionné par la rétention: ne compte pas dans le quota.
+        trash.seed("ancien.txt", "/tmp", Duration::days(60), 10 * gb);
+        let mut config_app = ConfigApp {
+            size_quota_enabled: true,
+            size_quota_gb: 2.0,
+            ..config()
+        };
+
+        let selection = get_elements_to_process(
+            &mut ConsoleApp::default(),
+            &mut config_app,
+            &trash,
+            &Progress::default(),
+        )
+        .unwrap();
+
+        let selected: Vec<(&str, SelectionRule)> = selection
+            .candidates
+            .iter()
+            .map(|c| (c.item.name.as_str(), c.rule))
+            .collect();
+        assert_eq!(
+            selected,
+            [
+                ("ancien.txt", SelectionRule::Age),
+                ("récent-5.txt", SelectionRule::SizeQuota),
+                ("récent-4.txt", SelectionRule::SizeQuota),
+                ("récent-2.txt", SelectionRule::SizeQuota),
+            ]
+        );
+        // L'élément protégé est passé sans réduire la taille restante.
+        assert_eq!(selection.protected.len(), 1);
+        assert_eq!(selection.protected[0].0.name, "récent-3.psd");
+    }
+
+    #[test]
+    fn quota_counts_protected_items_past_retention() {
+        let gb = consts::BYTES_PER_GB as u64;
+        let trash = MemoryTrash::new();
+        // Protégé malgré son âge: il reste dans la corbeille et occupe sa place.
+        trash.seed("ancien.psd", "/tmp", Duration::days(60), 2 * gb);
+        trash.seed("récent-2.txt", "/tmp", Duration::days(2), gb);
+        trash.seed("récent-1.txt", "/tmp", Duration::days(1), gb);
+        let mut config_app = ConfigApp {
+            size_quota_enabled: true,
+            size_quota_gb: 3.0,
+            ..config()
+        };
+
+        let selection = get_elements_to_process(
+            &mut ConsoleApp::default(),
+            &mut config_app,
+            &trash,
+            &Progress::default(),
+        )
+        .unwrap();
+
+        let selected: Vec<(&str, SelectionRule)> = selection
+            .candidates
+            .iter()
+            .map(|c| (c.item.name.as_str(), c.rule))
+            .collect();
+        assert_eq!(selected, [("récent-2.txt", SelectionRule::SizeQuota)]);
+        assert_eq!(selection.protected.len(), 1);
+        assert_eq!(selection.protected[0].0.name, "ancien.psd");
+    }
+
+    #[test]
+    fn quota_counts_folders_as_empty() {
+        let gb = consts::BYTES_PER_GB as u64;
+        let trash = MemoryTrash::new();
+        trash.seed_folder("dossier", "/tmp", Duration::days(5), 12);
+        trash.seed("récent-4.txt", "/tmp", Duration::days(4), gb);
+        trash.seed("récent-3.txt", "/tmp", Duration::days(3), gb);
+        trash.seed("récent-1.txt", "/tmp", Duration::days(1), gb);
+        let mut config_app = ConfigApp {
+            size_quota_enabled: true,
+            size_quota_gb: 2.0,
+            ..config()
+        };
+
+        let selection = get_elements_to_process(
+            &mut ConsoleApp::default(),
+            &mut config_app,
+            &trash,
+            &Progress::default(),
+        )
+        .unwrap();
+
+        // Le dossier, plus ancien, part sans réduire la taille restante.
+        let selected: Vec<(&str, Option<u64>)> = selection
+            .candidates
+            .iter()
+            .map(|c| (c.item.name.as_str(), c.size))
+            .collect();
+        assert_eq!(selected, [("dossier", None), ("récent-4.txt", Some(gb))]);
+    }
+
+    #[test]
+    fn an_unavailable_quarantine_keeps_the_items_in_the_trash() {
+        let trash = seeded();
+        let mut console_app = ConsoleApp::default();
+        let config_app = ConfigApp {
+            quarantine_enabled: true,
+            ..config()
+        };
+        let candidates = get_elements_to_process(
+            &mut console_app,
+            &mut config_app.clone(),
+            &trash,
+            &Progress::default(),
+        )
+        .unwrap()
+        .candidates;
+        let report = purger(
+            &mut console_app,
+            &config_app,
+            &trash,
+            None,
+            candidates,
+            None,
+            &Progress::default(),
+        );
+
+        assert_eq!(report.outcome, Outcome::PartialFailure);
+        assert!(report
+            .items
+            .iter()
+            .all(|record| record.status == ItemStatus::Failed));
+        assert_eq!(trash.len(), 4);
+    }
+
+    #[test]
+    fn a_purge_is_written_to_the_given_audit_log() {
+        let trash = seeded();
+        let mut console_app = ConsoleApp::default();
+        let config_app = config();
+        let candidates = get_elements_to_process(
+            &mut console_app,
+            &mut config_app.clone(),
+            &trash,
+            &Progress::default(),
+        )
+        .unwrap()
+        .candidates;
+        let data = DataDir::new("audit");
+        supprimer_selection(
+            &mut console_app,
+            &config_app,
+            &trash,
+            Some(&data.quarantine()),
+            Some(&data.audit_log()),
+            candidates,
+            &Progress::default(),
+        );
+
+        let entries = data.audit_log().read().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].trigger, PurgeTrigger::Interface);
+        assert_eq!(entries[0].purged_count(), 2);
+    }
 }
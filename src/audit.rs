@@ -0,0 +1,205 @@
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{ConfigApp, Outcome};
+use crate::format::rfc3339;
+use crate::i18n::{tr, trf};
+use crate::report::{ErrorKind, ItemRecord, ItemStatus, Report};
+use crate::retention::Retention;
+use crate::rules::ProtectionRule;
+
+/// Origine d'une suppression définitive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PurgeTrigger {
+    /// Suppression confirmée dans l'interface.
+    Interface,
+    Scheduled,
+    CommandLine,
+    /// Fin de la période de quarantaine.
+    QuarantineExpiry,
+}
+
+impl PurgeTrigger {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PurgeTrigger::Interface => tr("trigger.interface"),
+            PurgeTrigger::Scheduled => tr("trigger.scheduled"),
+            PurgeTrigger::CommandLine => tr("trigger.command_line"),
+            PurgeTrigger::QuarantineExpiry => tr("trigger.quarantine_expiry"),
+        }
+    }
+}
+
+/// Politique de rétention en vigueur lors d'une suppression.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditPolicy {
+    /// Les anciennes entrées ne contiennent qu'un nombre de jours.
+    #[serde(alias = "days")]
+    pub retention: Retention,
+    /// Taille maximale en Go, si elle est activée.
+    pub size_quota_gb: Option<f64>,
+    pub protection_rules: Vec<ProtectionRule>,
+    /// Durée de la quarantaine en jours, si elle est activée.
+    #[serde(default)]
+    pub quarantine_days: Option<u16>,
+}
+
+impl AuditPolicy {
+    pub fn from_config(config_app: &ConfigApp) -> Self {
+        Self {
+            retention: config_app.time_threshold,
+            size_quota_gb: config_app
+                .size_quota_enabled
+                .then_some(config_app.size_quota_gb),
+            protection_rules: config_app.protection_rules.clone(),
+            quarantine_days: config_app
+                .quarantine_enabled
+                .then_some(config_app.quarantine_days),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let mut description = trf("policy.keep", &[&self.retention]);
+        if let Some(quota) = self.size_quota_gb {
+            description.push_str(&trf("policy.size_quota", &[&quota]));
+        }
+        if !self.protection_rules.is_empty() {
+            description.push_str(&trf("policy.protections", &[&self.protection_rules.len()]));
+        }
+        if let Some(days) = self.quarantine_days {
+            description.push_str(&trf("policy.quarantine", &[&days]));
+        }
+        description
+    }
+}
+
+/// Élément traité lors d'une suppression.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditItem {
+    pub name: String,
+    pub original_path: String,
+    /// Date de mise à la corbeille au format RFC 3339.
+    pub time_deleted: String,
+    pub size: Option<u64>,
+    pub status: ItemStatus,
+    pub error: Option<String>,
+    pub error_kind: Option<ErrorKind>,
+}
+
+impl From<&ItemRecord> for AuditItem {
+    fn from(record: &ItemRecord) -> Self {
+        Self {
+            name: record.name.clone(),
+            original_path: record.original_path.clone(),
+            time_deleted: rfc3339(record.item.time_deleted),
+            size: record.size,
+            status: record.status,
+            error: record.error.clone(),
+            error_kind: record.error_kind,
+        }
+    }
+}
+
+/// Une ligne du journal d'audit: une exécution de suppression définitive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Date de l'exécution au format RFC 3339, heure locale.
+    pub timestamp: String,
+    pub trigger: PurgeTrigger,
+    pub policy: AuditPolicy,
+    pub outcome: Outcome,
+    pub items: Vec<AuditItem>,
+}
+
+impl AuditEntry {
+    pub fn new(trigger: PurgeTrigger, config_app: &ConfigApp, report: &Report) -> Self {
+        Self {
+            timestamp: Local::now().to_rfc3339(),
+            trigger,
+            policy: AuditPolicy::from_config(config_app),
+            outcome: report.outcome,
+            items: report.items.iter().map(AuditItem::from).collect(),
+        }
+    }
+
+    pub fn purged_count(&self) -> usize {
+        self.purged().count()
+    }
+
+    pub fn quarantined_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.status == ItemStatus::Quarantined)
+            .count()
+    }
+
+    pub fn purged_size(&self) -> u64 {
+        self.purged().filter_map(|item| item.size).sum()
+    }
+
+    fn purged(&self) -> impl Iterator<Item = &AuditItem> + Clone {
+        self.items
+            .iter()
+            .filter(|item| item.status == ItemStatus::Purged)
+    }
+}
+
+/// Journal des suppressions définitives, au format JSON Lines.
+///
+/// Le fichier n'est jamais réécrit: chaque exécution y ajoute une ligne.
+#[derive(Clone)]
+pub struct AuditLog {
+    path: PathBuf,
+}
+
+impl AuditLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Journal du dossier de données de l'application, `None` si ce dossier
+    /// est introuvable.
+    pub fn in_data_dir() -> Option<Self> {
+        crate::data_dir().map(|directory| Self::new(directory.join("audit.jsonl")))
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn append(&self, entry: &AuditEntry) -> std::io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Une seule écriture par ligne pour ne pas mélanger deux processus.
+        let mut line = serde_json::to_vec(entry).map_err(std::io::Error::from)?;
+        line.push(b'\n');
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?
+            .write_all(&line)
+    }
+
+    /// Lit toutes les exécutions, de la plus ancienne à la plus récente.
+    /// Les lignes illisibles sont ignorées.
+    pub fn read(&self) -> std::io::Result<Vec<AuditEntry>> {
+        let file = match fs::File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut entries = Vec::new();
+        for line in BufReader::new(file).lines() {
+            if let Ok(entry) = serde_json::from_str(&line?) {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+}
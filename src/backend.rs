@@ -0,0 +1,207 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use chrono::{Duration, Local};
+use trash::{TrashItem, TrashItemMetadata, TrashItemSize};
+
+/// Accès à la corbeille utilisé par l'application.
+///
+/// Toutes les opérations de l'interface passent par ce trait afin de pouvoir
+/// remplacer la vraie corbeille par une corbeille en mémoire.
+pub trait TrashBackend: Send + Sync {
+    /// Liste tous les éléments présents dans la corbeille.
+    fn list(&self) -> Result<Vec<TrashItem>, trash::Error>;
+
+    /// Supprime définitivement les éléments fournis.
+    fn purge(&self, items: Vec<TrashItem>) -> Result<(), trash::Error>;
+
+    /// Restaure les éléments fournis dans leur dossier d'origine.
+    fn restore(&self, items: Vec<TrashItem>) -> Result<(), trash::Error>;
+
+    /// Retourne les métadonnées (taille) d'un élément.
+    fn metadata(&self, item: &TrashItem) -> Result<TrashItemMetadata, trash::Error>;
+
+    /// Met à la corbeille le fichier ou dossier `path`.
+    fn delete(&self, path: &Path) -> Result<(), trash::Error>;
+}
+
+//_______________________________ SYSTEM TRASH ________________________________
+
+/// Corbeille du système d'exploitation, via le crate `trash`.
+#[derive(Default)]
+pub struct SystemTrash;
+
+impl TrashBackend for SystemTrash {
+    fn list(&self) -> Result<Vec<TrashItem>, trash::Error> {
+        trash::os_limited::list()
+    }
+
+    fn purge(&self, items: Vec<TrashItem>) -> Result<(), trash::Error> {
+        trash::os_limited::purge_all(items)
+    }
+
+    fn restore(&self, items: Vec<TrashItem>) -> Result<(), trash::Error> {
+        trash::os_limited::restore_all(items)
+    }
+
+    fn metadata(&self, item: &TrashItem) -> Result<TrashItemMetadata, trash::Error> {
+        trash::os_limited::metadata(item)
+    }
+
+    fn delete(&self, path: &Path) -> Result<(), trash::Error> {
+        trash::delete(path)
+    }
+}
+
+//_______________________________ MEMORY TRASH ________________________________
+
+/// Corbeille en mémoire pour les tests et les démonstrations.
+///
+/// Les éléments sont ajoutés avec [`MemoryTrash::seed`] en précisant depuis
+/// combien de temps ils sont dans la corbeille.
+#[derive(Default)]
+pub struct MemoryTrash {
+    items: Mutex<Vec<(TrashItem, TrashItemMetadata)>>,
+    next_id: AtomicU64,
+}
+
+impl MemoryTrash {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ajoute un élément mis à la corbeille il y a `age` et retourne une copie.
+    pub fn seed(
+        &self,
+        name: &str,
+        original_parent: impl Into<PathBuf>,
+        age: Duration,
+        size: u64,
+    ) -> TrashItem {
+        self.seed_with(name, original_parent, age, TrashItemSize::Bytes(size))
+    }
+
+    /// Ajoute un dossier de `entries` éléments, dont la taille en octets est
+    /// inconnue comme dans la corbeille du système.
+    pub fn seed_folder(
+        &self,
+        name: &str,
+        original_parent: impl Into<PathBuf>,
+        age: Duration,
+        entries: usize,
+    ) -> TrashItem {
+        self.seed_with(name, original_parent, age, TrashItemSize::Entries(entries))
+    }
+
+    fn seed_with(
+        &self,
+        name: &str,
+        original_parent: impl Into<PathBuf>,
+        age: Duration,
+        size: TrashItemSize,
+    ) -> TrashItem {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let item = TrashItem {
+            id: OsString::from(format!("memory-{}", id)),
+            name: name.to_owned(),
+            original_parent: original_parent.into(),
+            time_deleted: (Local::now() - age).timestamp(),
+        };
+        let metadata = TrashItemMetadata { size };
+        self.items.lock().unwrap().push((item.clone(), metadata));
+        item
+    }
+
+    /// Ajoute un élément déjà construit avec ses métadonnées.
+    pub fn insert(&self, item: TrashItem, metadata: TrashItemMetadata) {
+        self.items.lock().unwrap().push((item, metadata));
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.lock().unwrap().is_empty()
+    }
+
+    fn take(&self, items: &[TrashItem]) -> Result<(), trash::Error> {
+        let mut store = self.items.lock().unwrap();
+        if let Some(missing) = items
+            .iter()
+            .find(|item| !store.iter().any(|(stored, _)| stored.id == item.id))
+        {
+            return Err(trash::Error::CouldNotAccess {
+                target: missing.name.clone(),
+            });
+        }
+        store.retain(|(stored, _)| !items.iter().any(|item| item.id == stored.id));
+        Ok(())
+    }
+}
+
+impl TrashBackend for MemoryTrash {
+    fn list(&self) -> Result<Vec<TrashItem>, trash::Error> {
+        Ok(self
+            .items
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(item, _)| item.clone())
+            .collect())
+    }
+
+    fn purge(&self, items: Vec<TrashItem>) -> Result<(), trash::Error> {
+        self.take(&items)
+    }
+
+    fn restore(&self, items: Vec<TrashItem>) -> Result<(), trash::Error> {
+        // Même comportement que le crate `trash` en cas de conflit de nom.
+        if let Some(item) = items.iter().find(|item| item.original_path().exists()) {
+            return Err(trash::Error::RestoreCollision {
+                path: item.original_path(),
+                remaining_items: items.clone(),
+            });
+        }
+        self.take(&items)
+    }
+
+    fn metadata(&self, item: &TrashItem) -> Result<TrashItemMetadata, trash::Error> {
+        self.items
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(stored, _)| stored.id == item.id)
+            .map(|(_, metadata)| *metadata)
+            .ok_or_else(|| trash::Error::CouldNotAccess {
+                target: item.name.clone(),
+            })
+    }
+
+    /// Ajoute l'élément sans toucher au disque: comme pour la restauration,
+    /// la corbeille en mémoire ne garde que sa trace.
+    fn delete(&self, path: &Path) -> Result<(), trash::Error> {
+        let metadata = path.metadata().map_err(|_| trash::Error::CouldNotAccess {
+            target: path.display().to_string(),
+        })?;
+        let size = if metadata.is_dir() {
+            TrashItemSize::Entries(path.read_dir().map_or(0, |entries| entries.count()))
+        } else {
+            TrashItemSize::Bytes(metadata.len())
+        };
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let item = TrashItem {
+            id: OsString::from(format!("memory-{}", id)),
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            original_parent: path.parent().map(Path::to_path_buf).unwrap_or_default(),
+            time_deleted: Local::now().timestamp(),
+        };
+        self.insert(item, TrashItemMetadata { size });
+        Ok(())
+    }
+}
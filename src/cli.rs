@@ -0,0 +1,464 @@
+use std::path::PathBuf;
+
+use chrono::Local;
+
+use crate::app::{
+    afficher_analyse, analyser, comparer_profils, suppression_planifiee, supprimer_definitivement,
+    supprimer_quarantaine_expiree, ConfigApp, ConsoleApp, Outcome,
+};
+use crate::audit::AuditLog;
+use crate::backend::{SystemTrash, TrashBackend};
+use crate::config_file::ConfigFile;
+use crate::console::Category;
+use crate::consts;
+use crate::format::{format_date, format_size, is_valid_strftime, set_date_settings, DateStyle};
+use crate::i18n::{set_language, tr, trf, Language};
+use crate::quarantine::Quarantine;
+use crate::report::ExportFormat;
+use crate::retention::Retention;
+use crate::rules::ProtectionRule;
+use crate::scheduler::ScheduleState;
+use crate::worker::Progress;
+
+/// Aide de la ligne de commande, dans la langue courante.
+pub fn usage() -> &'static str {
+    tr("cli.usage")
+}
+
+/// Code de sortie pour des arguments invalides.
+pub const EXIT_USAGE: i32 = 1;
+
+impl Outcome {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Outcome::Success => 0,
+            // Le mode ligne de commande ne peut pas être annulé en cours de route.
+            Outcome::PartialFailure | Outcome::Cancelled => 2,
+            Outcome::ListingError => 3,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Analyze,
+    Purge,
+    Schedule,
+    Help,
+}
+
+#[derive(Debug)]
+struct Args {
+    command: Command,
+    /// Fichier de configuration de base, complété par les autres options.
+    config: Option<PathBuf>,
+    /// Profil du fichier de configuration à appliquer.
+    profile: Option<String>,
+    /// Profil auquel comparer l'analyse.
+    compare: Option<String>,
+    retention: Option<Retention>,
+    max_size_gb: Option<f64>,
+    protection_rules: Vec<ProtectionRule>,
+    quarantine_days: Option<u16>,
+    format: Option<ExportFormat>,
+    /// Heure des exécutions planifiées.
+    at: Option<(u8, u8)>,
+    every: Option<u16>,
+    max_items: Option<u32>,
+    max_total_gb: Option<f64>,
+    language: Option<Language>,
+    date_style: Option<DateStyle>,
+    /// Format strftime du style personnalisé.
+    date_custom: Option<String>,
+    utc: bool,
+    relative: bool,
+    yes: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut iter = args.iter();
+    let command = match iter.next().map(String::as_str) {
+        Some("analyze") => Command::Analyze,
+        Some("purge") => Command::Purge,
+        Some("schedule") => Command::Schedule,
+        Some("help") | Some("--help") | Some("-h") | None => Command::Help,
+        Some(other) => return Err(trf("cli.unknown_command", &[&other])),
+    };
+
+    let mut parsed = Args {
+        command,
+        config: None,
+        profile: None,
+        compare: None,
+        retention: None,
+        max_size_gb: None,
+        protection_rules: Vec::new(),
+        quarantine_days: None,
+        format: None,
+        at: None,
+        every: None,
+        max_items: None,
+        max_total_gb: None,
+        language: None,
+        date_style: None,
+        date_custom: None,
+        utc: false,
+        relative: false,
+        yes: false,
+    };
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--config" => {
+                let value = iter.next().ok_or_else(|| trf("cli.expects_path", &[arg]))?;
+                parsed.config = Some(PathBuf::from(value));
+            }
+            "--profile" | "--compare" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| trf("cli.expects_profile", &[arg]))?;
+                if arg == "--profile" {
+                    parsed.profile = Some(value.clone());
+                } else {
+                    parsed.compare = Some(value.clone());
+                }
+            }
+            "--days" | "-d" => {
+                let value = iter.next().ok_or_else(|| trf("cli.expects_days", &[arg]))?;
+                let days = value
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|days| *days >= 1)
+                    .ok_or_else(|| trf("cli.invalid_days", &[value]))?;
+                parsed.retention = Some(Retention::days(days));
+            }
+            "--keep" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| trf("cli.expects_retention", &[arg]))?;
+                let retention = value
+                    .parse::<Retention>()
+                    .map_err(|()| trf("cli.invalid_retention", &[value]))?;
+                parsed.retention = Some(retention);
+            }
+            "--max-size" => {
+                let value = iter.next().ok_or_else(|| trf("cli.expects_size", &[arg]))?;
+                let size = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|size| *size >= 0.0)
+                    .ok_or_else(|| trf("cli.invalid_size", &[value]))?;
+                parsed.max_size_gb = Some(size);
+            }
+            "--protect-name" | "--protect-folder" | "--protect-ext" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| trf("cli.expects_pattern", &[arg]))?;
+                let rule = match arg.as_str() {
+                    "--protect-name" => ProtectionRule::NameGlob(value.clone()),
+                    "--protect-folder" => ProtectionRule::FolderPrefix(value.clone()),
+                    _ => ProtectionRule::Extension(value.clone()),
+                };
+                rule.validate()?;
+                parsed.protection_rules.push(rule);
+            }
+            "--quarantine" => {
+                let value = iter.next().ok_or_else(|| trf("cli.expects_days", &[arg]))?;
+                let days = value
+                    .parse::<u16>()
+                    .ok()
+                    .filter(|days| *days >= 1)
+                    .ok_or_else(|| trf("cli.invalid_days", &[value]))?;
+                parsed.quarantine_days = Some(days);
+            }
+            "--format" | "-f" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| trf("cli.expects_format", &[arg]))?;
+                parsed.format = match value.as_str() {
+                    "text" => None,
+                    name => Some(
+                        ExportFormat::from_name(name)
+                            .ok_or_else(|| trf("cli.unknown_format", &[&name]))?,
+                    ),
+                };
+            }
+            "--at" => {
+                let value = iter.next().ok_or_else(|| trf("cli.expects_time", &[arg]))?;
+                let (hour, minute) = value
+                    .split_once(':')
+                    .and_then(|(hour, minute)| Some((hour.parse().ok()?, minute.parse().ok()?)))
+                    .filter(|(hour, minute): &(u8, u8)| *hour < 24 && *minute < 60)
+                    .ok_or_else(|| trf("cli.invalid_time", &[value]))?;
+                parsed.at = Some((hour, minute));
+            }
+            "--every" => {
+                let value = iter.next().ok_or_else(|| trf("cli.expects_days", &[arg]))?;
+                let days = value
+                    .parse::<u16>()
+                    .ok()
+                    .filter(|days| *days >= 1)
+                    .ok_or_else(|| trf("cli.invalid_days", &[value]))?;
+                parsed.every = Some(days);
+            }
+            "--max-items" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| trf("cli.expects_number", &[arg]))?;
+                let items = value
+                    .parse::<u32>()
+                    .map_err(|_| trf("cli.invalid_item_count", &[value]))?;
+                parsed.max_items = Some(items);
+            }
+            "--max-total" => {
+                let value = iter.next().ok_or_else(|| trf("cli.expects_size", &[arg]))?;
+                let size = value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|size| *size >= 0.0)
+                    .ok_or_else(|| trf("cli.invalid_size", &[value]))?;
+                parsed.max_total_gb = Some(size);
+            }
+            "--lang" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| trf("cli.expects_language", &[arg]))?;
+                parsed.language = Some(
+                    Language::from_code(value)
+                        .ok_or_else(|| trf("cli.unknown_language", &[value]))?,
+                );
+            }
+            "--date-format" => {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| trf("cli.expects_date_format", &[arg]))?;
+                match value.as_str() {
+                    "iso" => parsed.date_style = Some(DateStyle::Iso8601),
+                    "locale" => parsed.date_style = Some(DateStyle::Locale),
+                    pattern if is_valid_strftime(pattern) => {
+                        parsed.date_style = Some(DateStyle::Custom);
+                        parsed.date_custom = Some(pattern.to_owned());
+                    }
+                    _ => return Err(trf("cli.invalid_date_format", &[value])),
+                }
+            }
+            "--utc" => parsed.utc = true,
+            "--relative" => parsed.relative = true,
+            "--yes" | "-y" => parsed.yes = true,
+            other => return Err(trf("cli.unknown_option", &[&other])),
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Point d'entrée du mode ligne de commande. Retourne le code de sortie du processus.
+pub fn run(args: &[String]) -> i32 {
+    run_with_backend(args, &SystemTrash)
+}
+
+pub fn run_with_backend(args: &[String], backend: &dyn TrashBackend) -> i32 {
+    set_language(Language::from_system());
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, usage());
+            return EXIT_USAGE;
+        }
+    };
+
+    // Les options de la ligne de commande priment sur le fichier de configuration.
+    let config_file = match &args.config {
+        Some(path) => match ConfigFile::load(path) {
+            Ok(config_file) => config_file,
+            Err(e) => {
+                eprintln!("{}", trf("cli.config_error", &[&path.display(), &e]));
+                return EXIT_USAGE;
+            }
+        },
+        None => ConfigFile::default(),
+    };
+    set_language(args.language.unwrap_or(config_file.language));
+
+    let mut config_app = config_file.config;
+    if let Some(name) = &args.profile {
+        if !config_app.switch_profile(name) {
+            eprintln!("{}", trf("cli.unknown_profile", &[name]));
+            return EXIT_USAGE;
+        }
+    }
+    let compare = match &args.compare {
+        Some(name) => match config_app.profile(name) {
+            Some(profile) => Some(profile.clone()),
+            None => {
+                eprintln!("{}", trf("cli.unknown_profile", &[name]));
+                return EXIT_USAGE;
+            }
+        },
+        None => None,
+    };
+    if let Some(style) = args.date_style {
+        config_app.date.style = style;
+    }
+    if let Some(custom) = args.date_custom {
+        config_app.date.custom = custom;
+    }
+    config_app.date.utc |= args.utc;
+    config_app.date.relative |= args.relative;
+    set_date_settings(&config_app.date);
+
+    if let Some(retention) = args.retention {
+        config_app.time_threshold = retention;
+    }
+    config_app.protection_rules.extend(args.protection_rules);
+    if let Some(size) = args.max_size_gb {
+        config_app.size_quota_enabled = true;
+        config_app.size_quota_gb = size;
+    }
+    if let Some((hour, minute)) = args.at {
+        config_app.schedule.hour = hour;
+        config_app.schedule.minute = minute;
+    }
+    if let Some(days) = args.every {
+        config_app.schedule.interval_days = days;
+    }
+    if let Some(items) = args.max_items {
+        config_app.schedule.max_items = items;
+    }
+    if let Some(size) = args.max_total_gb {
+        config_app.schedule.max_size_gb = size;
+    }
+    if let Some(days) = args.quarantine_days {
+        config_app.quarantine_enabled = true;
+        config_app.quarantine_days = days;
+    }
+    // En sortie structurée, seul le rapport est écrit sur stdout.
+    let mut console_app = match args.format {
+        Some(_) => ConsoleApp::default(),
+        None => ConsoleApp::stdout(),
+    };
+    let quarantine = Quarantine::in_data_dir();
+    let audit_log = AuditLog::in_data_dir();
+
+    let report = match args.command {
+        Command::Help => {
+            println!("{}", usage());
+            return Outcome::Success.exit_code();
+        }
+        Command::Analyze => match &compare {
+            Some(other) => comparer_profils(
+                &mut console_app,
+                &mut config_app,
+                other,
+                backend,
+                &Progress::default(),
+            ),
+            None => {
+                let report = analyser(
+                    &mut console_app,
+                    &mut config_app,
+                    backend,
+                    &Progress::default(),
+                );
+                afficher_analyse(&mut console_app, &report);
+                report
+            }
+        },
+        Command::Purge | Command::Schedule if !args.yes => {
+            eprintln!("{}", tr("cli.confirm_required"));
+            return EXIT_USAGE;
+        }
+        Command::Purge => supprimer_definitivement(
+            &mut console_app,
+            &mut config_app,
+            backend,
+            quarantine.as_ref(),
+            audit_log.as_ref(),
+            &Progress::default(),
+        ),
+        Command::Schedule => {
+            config_app.schedule.enabled = true;
+            planifier(
+                &mut console_app,
+                &mut config_app,
+                backend,
+                quarantine.as_ref(),
+                audit_log.as_ref(),
+                args.format,
+            )
+        }
+    };
+
+    if let Some(format) = args.format {
+        let stdout = std::io::stdout();
+        if let Err(e) = report.write_to(format, stdout.lock()) {
+            eprintln!("{}", trf("cli.report_write_error", &[&e]));
+        }
+    }
+
+    report.outcome.exit_code()
+}
+
+/// Mode planifié: applique la politique à chaque créneau jusqu'à l'arrêt du processus.
+fn planifier(
+    console_app: &mut ConsoleApp,
+    config_app: &mut ConfigApp,
+    backend: &dyn TrashBackend,
+    quarantine: Option<&Quarantine>,
+    audit_log: Option<&AuditLog>,
+    format: Option<ExportFormat>,
+) -> ! {
+    let mut state = ScheduleState::default();
+    let progress = Progress::default();
+    let mut announced = None;
+    loop {
+        let now = Local::now();
+        supprimer_quarantaine_expiree(console_app, config_app, quarantine, audit_log);
+        if state.is_due(&config_app.schedule, now) {
+            let (run, report) = suppression_planifiee(
+                console_app,
+                config_app,
+                backend,
+                quarantine,
+                audit_log,
+                &progress,
+            );
+            console_app.info(
+                Category::Schedule,
+                &trf(
+                    "cli.scheduled_run",
+                    &[
+                        &run.status.label(),
+                        &run.items,
+                        &format_size(Some(run.size)),
+                        &run.detail,
+                    ],
+                ),
+            );
+            if let (Some(format), Some(report)) = (format, report) {
+                let stdout = std::io::stdout();
+                if let Err(e) = report.write_to(format, stdout.lock()) {
+                    eprintln!("{}", trf("cli.report_write_error", &[&e]));
+                }
+            }
+            state.record(run);
+        }
+
+        let next_run = state.next_run(&config_app.schedule);
+        if next_run != announced {
+            if let Some(next_run) = next_run {
+                console_app.info(
+                    Category::Schedule,
+                    &trf("schedule.next_run", &[&format_date(next_run.timestamp())]),
+                );
+            }
+            announced = next_run;
+        }
+        // Attente par tranches d'une minute pour rester juste après une mise en veille.
+        let delay = next_run
+            .and_then(|next_run| (next_run - now).to_std().ok())
+            .unwrap_or_default()
+            .clamp(std::time::Duration::from_secs(1), consts::SCHEDULER_TICK);
+        std::thread::sleep(delay);
+    }
+}
@@ -0,0 +1,220 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::ConfigApp;
+use crate::i18n::{trf, Language};
+use crate::retention::Retention;
+use crate::rules::ProtectionRule;
+
+/// Configuration enregistrée dans un fichier TOML, modifiable à la main et
+/// partageable entre plusieurs postes. Les clés absentes gardent leur valeur par défaut.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfigFile {
+    pub language: Language,
+    #[serde(flatten)]
+    pub config: ConfigApp,
+}
+
+/// Clé `language` seule, lue à part du reste de la configuration.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct LanguageKey {
+    language: Language,
+}
+
+impl ConfigFile {
+    pub fn new(config: &ConfigApp, language: Language) -> Self {
+        Self {
+            language,
+            config: config.clone(),
+        }
+    }
+
+    /// Lit un fichier de configuration. Une erreur de syntaxe est signalée
+    /// comme `InvalidData`, avec la ligne concernée, tout comme une valeur
+    /// invalide, avec le nom du champ.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+        // Avec `flatten`, les erreurs perdent leur position: la langue et la
+        // configuration sont lues séparément.
+        let LanguageKey { language } = toml::from_str(&text).map_err(invalid)?;
+        let config_file = Self {
+            language,
+            config: toml::from_str(&text).map_err(invalid)?,
+        };
+        config_file.validate().map_err(|field| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                trf("config.invalid_value", &[&field]),
+            )
+        })?;
+        Ok(config_file)
+    }
+
+    /// Vérifie les valeurs qu'une modification à la main a pu rendre
+    /// dangereuses ou inutilisables. Retourne le nom du premier champ invalide.
+    fn validate(&self) -> Result<(), String> {
+        let config = &self.config;
+        check_retention("time_threshold", config.time_threshold)?;
+        check_size("size_quota_gb", config.size_quota_gb)?;
+        check_size(
+            "confirm_size_threshold_gb",
+            config.confirm_size_threshold_gb,
+        )?;
+        check("max_console_lines", config.max_console_lines >= 10)?;
+        check("quarantine_days", config.quarantine_days >= 1)?;
+        check("schedule.hour", config.schedule.hour <= 23)?;
+        check("schedule.minute", config.schedule.minute <= 59)?;
+        check("schedule.interval_days", config.schedule.interval_days >= 1)?;
+        check_size("schedule.max_size_gb", config.schedule.max_size_gb)?;
+        check_rules("protection_rules", &config.protection_rules)?;
+        for profile in &config.profiles {
+            let field = |name: &str| format!("profiles.{}.{}", profile.name, name);
+            check_retention(&field("time_threshold"), profile.time_threshold)?;
+            check_size(&field("size_quota_gb"), profile.size_quota_gb)?;
+            check_rules(&field("protection_rules"), &profile.protection_rules)?;
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text =
+            toml::to_string_pretty(self).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        fs::write(path, text)
+    }
+}
+
+fn check(field: &str, valid: bool) -> Result<(), String> {
+    if valid {
+        Ok(())
+    } else {
+        Err(field.to_owned())
+    }
+}
+
+/// Une durée nulle sélectionnerait toute la corbeille.
+fn check_retention(field: &str, retention: Retention) -> Result<(), String> {
+    check(field, retention.amount >= 1)
+}
+
+fn check_size(field: &str, size_gb: f64) -> Result<(), String> {
+    check(field, size_gb.is_finite() && size_gb >= 0.0)
+}
+
+fn check_rules(field: &str, rules: &[ProtectionRule]) -> Result<(), String> {
+    check(field, rules.iter().all(|rule| rule.validate().is_ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::retention::TimeUnit;
+    use crate::rules::ProtectionRule;
+
+    /// Fichier temporaire propre à chaque test, supprimé à la fin.
+    struct TempFile(std::path::PathBuf);
+
+    impl TempFile {
+        fn new(name: &str, content: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "windows_trash_manager-{}-{}.toml",
+                std::process::id(),
+                name
+            ));
+            fs::write(&path, content).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn round_trips_through_a_file() {
+        let mut config = ConfigApp {
+            time_threshold: Retention {
+                amount: 6,
+                unit: TimeUnit::Months,
+            },
+            size_quota_enabled: true,
+            size_quota_gb: 2.5,
+            protection_rules: vec![
+                ProtectionRule::NameGlob("*.psd".to_owned()),
+                ProtectionRule::FolderPrefix("C:\\Projets".to_owned()),
+            ],
+            ..Default::default()
+        };
+        config.schedule.enabled = true;
+        config.schedule.hour = 22;
+        config.schedule.interval_days = 7;
+        config.save_profile("Prudente");
+        let original = ConfigFile::new(&config, Language::English);
+
+        let file = TempFile::new("round_trip", "");
+        original.save(&file.0).unwrap();
+        let loaded = ConfigFile::load(&file.0).unwrap();
+
+        assert_eq!(loaded.language, Language::English);
+        assert_eq!(loaded.config.time_threshold, config.time_threshold);
+        assert_eq!(loaded.config.protection_rules, config.protection_rules);
+        assert_eq!(loaded.config.schedule, config.schedule);
+        assert_eq!(loaded.config.profiles, config.profiles);
+        assert_eq!(loaded.config.active_profile.as_deref(), Some("Prudente"));
+        assert_eq!(
+            toml::to_string_pretty(&loaded).unwrap(),
+            toml::to_string_pretty(&original).unwrap()
+        );
+    }
+
+    #[test]
+    fn missing_keys_keep_their_defaults() {
+        let file = TempFile::new("partial", "language = \"en\"\ntime_threshold = 90\n");
+        let loaded = ConfigFile::load(&file.0).unwrap();
+        let defaults = ConfigApp::default();
+        assert_eq!(loaded.language, Language::English);
+        assert_eq!(loaded.config.time_threshold, Retention::days(90));
+        assert_eq!(loaded.config.max_console_lines, defaults.max_console_lines);
+        assert_eq!(loaded.config.schedule, defaults.schedule);
+    }
+
+    #[test]
+    fn rejects_dangerous_values() {
+        let cases = [
+            ("time_threshold = 0", "time_threshold"),
+            (
+                "time_threshold = { amount = 0, unit = \"days\" }",
+                "time_threshold",
+            ),
+            ("[schedule]\nhour = 24", "schedule.hour"),
+            ("[schedule]\ninterval_days = 0", "schedule.interval_days"),
+            ("size_quota_gb = -1.0", "size_quota_gb"),
+            (
+                "[[profiles]]\nname = \"Vide\"\nsize_quota_gb = nan",
+                "profiles.Vide.size_quota_gb",
+            ),
+            (
+                "[[protection_rules]]\nkind = \"name_glob\"\npattern = \"[a-\"",
+                "protection_rules",
+            ),
+            (
+                "[[profiles]]\nname = \"Vide\"\n[[profiles.protection_rules]]\n\
+                 kind = \"name_glob\"\npattern = \"**a\"",
+                "profiles.Vide.protection_rules",
+            ),
+        ];
+        for (content, field) in cases {
+            let file = TempFile::new("invalid", content);
+            let error = ConfigFile::load(&file.0).err().expect(content);
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{}", content);
+            assert!(error.to_string().contains(field), "{}: {}", content, error);
+        }
+    }
+}
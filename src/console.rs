@@ -0,0 +1,522 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use chrono::Local;
+use comfy_table::{Cell, Row, Table};
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::consts;
+use crate::format::format_date_absolute;
+use crate::i18n::tr;
+
+/// Gravité d'une entrée de la console.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Level {
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+impl Level {
+    pub const ALL: [Level; 4] = [Level::Debug, Level::Info, Level::Warning, Level::Error];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Level::Debug => tr("level.debug"),
+            Level::Info => tr("level.info"),
+            Level::Warning => tr("level.warning"),
+            Level::Error => tr("level.error"),
+        }
+    }
+
+    /// Couleur du niveau, `None` pour la couleur du texte.
+    pub fn color(&self, visuals: &egui::Visuals) -> Option<egui::Color32> {
+        match self {
+            Level::Debug => Some(visuals.weak_text_color()),
+            Level::Info => None,
+            Level::Warning => Some(visuals.warn_fg_color),
+            Level::Error => Some(visuals.error_fg_color),
+        }
+    }
+}
+
+/// Opération à l'origine d'une entrée de la console.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Category {
+    General,
+    /// Lecture de la corbeille.
+    Trash,
+    Analysis,
+    Purge,
+    Restore,
+    Quarantine,
+    Schedule,
+    Export,
+    Settings,
+    /// Événements `tracing` de l'application et de ses dépendances.
+    Diagnostics,
+}
+
+impl Category {
+    pub const ALL: [Category; 10] = [
+        Category::General,
+        Category::Trash,
+        Category::Analysis,
+        Category::Purge,
+        Category::Restore,
+        Category::Quarantine,
+        Category::Schedule,
+        Category::Export,
+        Category::Settings,
+        Category::Diagnostics,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Category::General => tr("category.general"),
+            Category::Trash => tr("category.trash"),
+            Category::Analysis => tr("category.analysis"),
+            Category::Purge => tr("category.purge"),
+            Category::Restore => tr("category.restore"),
+            Category::Quarantine => tr("category.quarantine"),
+            Category::Schedule => tr("category.schedule"),
+            Category::Export => tr("category.export"),
+            Category::Settings => tr("category.settings"),
+            Category::Diagnostics => tr("category.diagnostics"),
+        }
+    }
+}
+
+/// Contenu d'une entrée: un message ou un tableau à afficher en police fixe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsoleContent {
+    Message(String),
+    Table(ConsoleTable),
+}
+
+/// Tableau de la console: ses cellules, pour les exports Markdown et HTML,
+/// et son rendu texte.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ConsoleTable {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    pub text: String,
+}
+
+impl From<&Table> for ConsoleTable {
+    fn from(table: &Table) -> Self {
+        let cells = |row: &Row| -> Vec<String> { row.cell_iter().map(Cell::content).collect() };
+        Self {
+            header: table.header().map(cells).unwrap_or_default(),
+            rows: table.row_iter().map(cells).collect(),
+            text: table.to_string(),
+        }
+    }
+}
+
+/// Accepte aussi l'ancien format persisté, le seul rendu texte du tableau.
+impl<'de> Deserialize<'de> for ConsoleTable {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "snake_case")]
+        enum Field {
+            Header,
+            Rows,
+            Text,
+            #[serde(other)]
+            Other,
+        }
+
+        struct TableVisitor;
+
+        impl<'de> Visitor<'de> for TableVisitor {
+            type Value = ConsoleTable;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a table text or a table with header, rows and text")
+            }
+
+            fn visit_str<E: de::Error>(self, text: &str) -> Result<ConsoleTable, E> {
+                Ok(ConsoleTable {
+                    text: text.to_owned(),
+                    ..Default::default()
+                })
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ConsoleTable, A::Error> {
+                let mut table = ConsoleTable::default();
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Header => table.header = map.next_value()?,
+                        Field::Rows => table.rows = map.next_value()?,
+                        Field::Text => table.text = map.next_value()?,
+                        Field::Other => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(table)
+            }
+        }
+
+        deserializer.deserialize_any(TableVisitor)
+    }
+}
+
+/// Une entrée de la console.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConsoleEntry {
+    /// Horodatage Unix de l'ajout.
+    pub timestamp: i64,
+    pub level: Level,
+    pub category: Category,
+    pub content: ConsoleContent,
+}
+
+impl ConsoleEntry {
+    pub fn new(level: Level, category: Category, content: ConsoleContent) -> Self {
+        Self {
+            timestamp: Local::now().timestamp(),
+            level,
+            category,
+            content,
+        }
+    }
+
+    /// Texte brut de l'entrée, avec ses lignes vides d'espacement.
+    pub fn text(&self) -> &str {
+        match &self.content {
+            ConsoleContent::Message(text) => text,
+            ConsoleContent::Table(table) => &table.text,
+        }
+    }
+
+    /// Date, niveau et catégorie de l'entrée, en tête des exports.
+    fn heading(&self) -> String {
+        format!(
+            "{} [{}] {}",
+            format_date_absolute(self.timestamp),
+            self.level.label(),
+            self.category.label()
+        )
+    }
+}
+
+/// Filtre de la console: niveaux affichés, catégorie et recherche de texte.
+pub struct ConsoleFilter {
+    pub levels: [bool; Level::ALL.len()],
+    /// `None` pour toutes les catégories.
+    pub category: Option<Category>,
+    pub search: String,
+}
+
+impl Default for ConsoleFilter {
+    fn default() -> Self {
+        Self {
+            levels: [true; Level::ALL.len()],
+            category: None,
+            search: String::new(),
+        }
+    }
+}
+
+impl ConsoleFilter {
+    pub fn shows_level(&self, level: Level) -> bool {
+        self.levels[level as usize]
+    }
+
+    pub fn matches(&self, entry: &ConsoleEntry) -> bool {
+        if !self.shows_level(entry.level) {
+            return false;
+        }
+        if self
+            .category
+            .map_or(false, |category| category != entry.category)
+        {
+            return false;
+        }
+        let search = self.search.trim();
+        search.is_empty() || entry.text().to_lowercase().contains(&search.to_lowercase())
+    }
+}
+
+/// Formats d'export de la console.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleFormat {
+    Text,
+    Markdown,
+    Html,
+}
+
+impl ConsoleFormat {
+    pub const ALL: [ConsoleFormat; 3] = [
+        ConsoleFormat::Text,
+        ConsoleFormat::Markdown,
+        ConsoleFormat::Html,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConsoleFormat::Text => tr("console_format.text"),
+            ConsoleFormat::Markdown => "Markdown",
+            ConsoleFormat::Html => "HTML",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ConsoleFormat::Text => "txt",
+            ConsoleFormat::Markdown => "md",
+            ConsoleFormat::Html => "html",
+        }
+    }
+}
+
+/// Écrit des entrées de la console dans le format demandé. En Markdown et en
+/// HTML, les tableaux d'analyse et de suppression gardent leurs cellules.
+pub fn write_entries<'a, W: Write>(
+    entries: impl IntoIterator<Item = &'a ConsoleEntry>,
+    format: ConsoleFormat,
+    mut writer: W,
+) -> io::Result<()> {
+    if format == ConsoleFormat::Html {
+        writeln!(
+            writer,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>",
+            escape_html(consts::APP_NAME)
+        )?;
+        writeln!(writer, "<style>{}</style>\n</head>\n<body>", HTML_STYLE)?;
+    }
+    for entry in entries {
+        let text = entry.text().trim_matches('\n');
+        match format {
+            ConsoleFormat::Text => match &entry.content {
+                ConsoleContent::Message(_) => writeln!(writer, "{}: {}", entry.heading(), text)?,
+                ConsoleContent::Table(_) => writeln!(writer, "{}:\n{}", entry.heading(), text)?,
+            },
+            ConsoleFormat::Markdown => {
+                write!(writer, "**{}**", escape_markdown(&entry.heading()))?;
+                match &entry.content {
+                    ConsoleContent::Message(_) => writeln!(
+                        writer,
+                        ": {}\n",
+                        escape_markdown(text).replace('\n', "  \n")
+                    )?,
+                    ConsoleContent::Table(table) if table.header.is_empty() => {
+                        let fence = code_fence(text);
+                        writeln!(writer, "\n\n{fence}text\n{}\n{fence}\n", text)?
+                    }
+                    ConsoleContent::Table(table) => {
+                        writeln!(writer, "\n")?;
+                        write_markdown_row(&mut writer, &table.header)?;
+                        writeln!(writer, "|{}", " --- |".repeat(table.header.len()))?;
+                        for row in &table.rows {
+                            write_markdown_row(&mut writer, row)?;
+                        }
+                        writeln!(writer)?;
+                    }
+                }
+            }
+            ConsoleFormat::Html => {
+                let class = match entry.level {
+                    Level::Debug => "debug",
+                    Level::Info => "info",
+                    Level::Warning => "warning",
+                    Level::Error => "error",
+                };
+                write!(
+                    writer,
+                    "<p class=\"{}\"><span class=\"heading\">{}</span>",
+                    class,
+                    escape_html(&entry.heading())
+                )?;
+                match &entry.content {
+                    ConsoleContent::Message(_) => {
+                        writeln!(writer, " {}</p>", escape_html(text).replace('\n', "<br>"))?
+                    }
+                    ConsoleContent::Table(table) if table.header.is_empty() => {
+                        writeln!(writer, "</p>\n<pre>{}</pre>", escape_html(text))?
+                    }
+                    ConsoleContent::Table(table) => {
+                        writeln!(writer, "</p>\n<table>\n<thead>")?;
+                        write_html_row(&mut writer, "th", &table.header)?;
+                        writeln!(writer, "</thead>\n<tbody>")?;
+                        for row in &table.rows {
+                            write_html_row(&mut writer, "td", row)?;
+                        }
+                        writeln!(writer, "</tbody>\n</table>")?;
+                    }
+                }
+            }
+        }
+    }
+    if format == ConsoleFormat::Html {
+        writeln!(writer, "</body>\n</html>")?;
+    }
+    writer.flush()
+}
+
+const HTML_STYLE: &str = "body { font-family: sans-serif; } \
+    .heading { color: #777; } .warning { color: #b26b00; } .error { color: #c00; } \
+    table { border-collapse: collapse; margin-bottom: 1em; } \
+    th, td { border: 1px solid #ccc; padding: 2px 8px; text-align: left; }";
+
+fn write_markdown_row<W: Write>(writer: &mut W, cells: &[String]) -> io::Result<()> {
+    write!(writer, "|")?;
+    for cell in cells {
+        write!(
+            writer,
+            " {} |",
+            escape_markdown(cell)
+                .replace('|', "\\|")
+                .replace('\n', "<br>")
+        )?;
+    }
+    writeln!(writer)
+}
+
+fn write_html_row<W: Write>(writer: &mut W, tag: &str, cells: &[String]) -> io::Result<()> {
+    write!(writer, "<tr>")?;
+    for cell in cells {
+        write!(
+            writer,
+            "<{tag}>{}</{tag}>",
+            escape_html(cell).replace('\n', "<br>")
+        )?;
+    }
+    writeln!(writer, "</tr>")
+}
+
+/// Clôture de bloc de code Markdown plus longue que toute suite d'accents
+/// graves du texte, qui fermerait le bloc trop tôt.
+fn code_fence(text: &str) -> String {
+    let longest = text
+        .split(|c| c != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or_default();
+    "`".repeat(longest.max(2) + 1)
+}
+
+/// Protège les caractères interprétés par Markdown (`**** Erreur… ****`).
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '&'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOSTILE: &str = "a < b && `code`\n```\n<script>";
+
+    fn message() -> ConsoleEntry {
+        ConsoleEntry::new(
+            Level::Error,
+            Category::General,
+            ConsoleContent::Message(HOSTILE.to_owned()),
+        )
+    }
+
+    fn table(header: &[&str]) -> ConsoleEntry {
+        let cells = |cells: &[&str]| cells.iter().map(|cell| cell.to_string()).collect();
+        ConsoleEntry::new(
+            Level::Info,
+            Category::General,
+            ConsoleContent::Table(ConsoleTable {
+                header: cells(header),
+                rows: vec![cells(&["x | y", "1 < 2\n&"])],
+                text: HOSTILE.to_owned(),
+            }),
+        )
+    }
+
+    fn render(entries: &[ConsoleEntry], format: ConsoleFormat) -> String {
+        let mut buffer = Vec::new();
+        write_entries(entries, format, &mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn text_keeps_the_raw_content() {
+        let entry = message();
+        let text = render(&[entry.clone(), table(&[])], ConsoleFormat::Text);
+
+        assert_eq!(
+            text,
+            format!(
+                "{}: {HOSTILE}\n{}:\n{HOSTILE}\n",
+                entry.heading(),
+                table(&[]).heading()
+            )
+        );
+    }
+
+    #[test]
+    fn markdown_escapes_messages() {
+        let entry = message();
+        let markdown = render(std::slice::from_ref(&entry), ConsoleFormat::Markdown);
+
+        assert_eq!(
+            markdown,
+            format!(
+                "**{}**: a \\< b \\&\\& \\`code\\`  \n\\`\\`\\`  \n\\<script\\>\n\n",
+                escape_markdown(&entry.heading())
+            )
+        );
+    }
+
+    #[test]
+    fn markdown_fence_outlasts_the_backticks() {
+        let markdown = render(&[table(&[])], ConsoleFormat::Markdown);
+
+        assert!(markdown.contains(&format!("\n\n````text\n{HOSTILE}\n````\n")));
+        assert_eq!(code_fence("pas d'accent"), "```");
+        assert_eq!(code_fence("`````"), "``````");
+    }
+
+    #[test]
+    fn markdown_tables_escape_cells() {
+        let markdown = render(&[table(&["a|b", "<c>"])], ConsoleFormat::Markdown);
+
+        assert!(markdown.contains("| a\\|b | \\<c\\> |\n| --- | --- |\n"));
+        assert!(markdown.contains("| x \\| y | 1 \\< 2<br>\\& |\n"));
+    }
+
+    #[test]
+    fn html_escapes_messages_and_tables() {
+        let html = render(
+            &[message(), table(&[]), table(&["<th>", "&"])],
+            ConsoleFormat::Html,
+        );
+
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.ends_with("</body>\n</html>\n"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains(" a &lt; b &amp;&amp; `code`<br>```<br>&lt;script&gt;</p>\n"));
+        assert!(html.contains("<pre>a &lt; b &amp;&amp; `code`\n```\n&lt;script&gt;</pre>"));
+        assert!(html.contains("<tr><th>&lt;th&gt;</th><th>&amp;</th></tr>"));
+        assert!(html.contains("<tr><td>x | y</td><td>1 &lt; 2<br>&amp;</td></tr>"));
+    }
+}
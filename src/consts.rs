@@ -3,6 +3,16 @@ pub const GITHUB_URL: &str = "https://github.com/julienwetzel/windows-trash-mana
 pub const GITHUB_URL_BLOB: &str =
     "https://github.com/julienwetzel/windows-trash-manager/blob/main/";
 
+// Nom de l'application, utilisé aussi par eframe pour le dossier de persistance
+pub const APP_NAME: &str = "Windows trash manager";
+
+// Taille d'un Go (Windows affiche les tailles en puissances de 1024)
+pub const BYTES_PER_GB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+// Intervalle maximal entre deux passages du planificateur, qui vérifie aussi
+// la quarantaine expirée
+pub const SCHEDULER_TICK: std::time::Duration = std::time::Duration::from_secs(60);
+
 pub mod emojis {
     /// Tux, the Linux penguin.
     pub const _GITHUB: char = '';
@@ -0,0 +1,588 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use chrono::{DateTime, Local};
+use egui::plot::{Bar, BarChart, Plot};
+use trash::TrashItem;
+
+use crate::format::{format_date, format_size};
+use crate::i18n::{tr, trf};
+use crate::retention::{Retention, TimeUnit};
+use crate::rules::{protecting_rule, ProtectionRule};
+
+/// Bornes des tranches d'âge, en multiples de la durée de rétention.
+const AGE_BOUNDS: [f64; 5] = [0.25, 0.5, 1.0, 2.0, 4.0];
+
+/// Libellés des tranches d'âge, en pourcentage de la durée de rétention.
+const AGE_LABELS: [&str; AGE_BOUNDS.len() + 1] = [
+    "< 25 %",
+    "25-50 %",
+    "50-100 %",
+    "100-200 %",
+    "200-400 %",
+    "> 400 %",
+];
+
+/// Index de la première tranche dont les éléments dépassent la durée de rétention.
+const FIRST_EXPIRED_BUCKET: usize = 3;
+
+/// Nombre de dossiers et d'extensions affichés dans les classements.
+const TOP_GROUPS: usize = 10;
+
+/// Durées de rétention comparées dans l'estimation de l'espace libéré.
+const SAVING_THRESHOLDS: [Retention; 5] = [
+    Retention {
+        amount: 1,
+        unit: TimeUnit::Weeks,
+    },
+    Retention {
+        amount: 1,
+        unit: TimeUnit::Months,
+    },
+    Retention {
+        amount: 3,
+        unit: TimeUnit::Months,
+    },
+    Retention {
+        amount: 6,
+        unit: TimeUnit::Months,
+    },
+    Retention {
+        amount: 12,
+        unit: TimeUnit::Months,
+    },
+];
+
+/// Contenu de la corbeille relevé pour le tableau de bord, avec la taille
+/// de chaque élément si elle est connue.
+pub struct TrashSnapshot {
+    pub items: Vec<(TrashItem, Option<u64>)>,
+    /// Horodatage Unix du relevé, référence pour l'âge des éléments.
+    pub taken_at: i64,
+}
+
+/// Nombre et taille cumulée d'un groupe d'éléments.
+#[derive(Debug, Clone, Default)]
+pub struct SizeGroup {
+    pub name: String,
+    pub count: usize,
+    pub size: u64,
+}
+
+impl SizeGroup {
+    fn add(&mut self, size: Option<u64>) {
+        self.count += 1;
+        self.size += size.unwrap_or(0);
+    }
+}
+
+/// Espace libéré si la durée de rétention était `retention`.
+#[derive(Debug, Clone)]
+pub struct Saving {
+    pub retention: Retention,
+    pub count: usize,
+    pub size: u64,
+}
+
+/// Statistiques d'un relevé pour une politique de rétention donnée.
+pub struct TrashStats {
+    /// Durée de rétention et règles de protection utilisées pour le calcul.
+    retention: Retention,
+    rules: Vec<ProtectionRule>,
+    pub total: SizeGroup,
+    /// Éléments dont la taille n'a pas pu être lue.
+    pub unknown_size: usize,
+    pub protected: usize,
+    pub oldest: Option<i64>,
+    /// Éléments par tranche d'âge (voir [`AGE_BOUNDS`]).
+    pub buckets: [SizeGroup; AGE_BOUNDS.len() + 1],
+    pub folders: Vec<SizeGroup>,
+    pub extensions: Vec<SizeGroup>,
+    /// Du seuil le plus court au plus long, avec la durée en vigueur.
+    pub savings: Vec<Saving>,
+}
+
+impl TrashStats {
+    /// Les éléments protégés restent comptés dans les totaux et les tranches
+    /// d'âge, mais jamais dans l'espace libéré.
+    pub fn compute(
+        snapshot: &TrashSnapshot,
+        retention: Retention,
+        rules: &[ProtectionRule],
+    ) -> Self {
+        let taken_at = DateTime::from_timestamp(snapshot.taken_at, 0)
+            .map(|time| time.with_timezone(&Local))
+            .unwrap_or_else(Local::now);
+        // Sans date limite représentable, aucun élément n'atteint la durée de rétention.
+        let threshold = retention.cutoff(taken_at).map_or(f64::INFINITY, |cutoff| {
+            (snapshot.taken_at - cutoff.timestamp()).max(1) as f64
+        });
+
+        let mut total = SizeGroup::default();
+        let mut unknown_size = 0;
+        let mut protected = 0;
+        let mut buckets: [SizeGroup; AGE_BOUNDS.len() + 1] = Default::default();
+        let mut folders: HashMap<String, SizeGroup> = HashMap::new();
+        let mut extensions: HashMap<String, SizeGroup> = HashMap::new();
+        for (item, size) in &snapshot.items {
+            total.add(*size);
+            unknown_size += usize::from(size.is_none());
+            protected += usize::from(protecting_rule(rules, item).is_some());
+
+            let ratio = (snapshot.taken_at - item.time_deleted).max(0) as f64 / threshold;
+            let bucket = AGE_BOUNDS
+                .iter()
+                .take_while(|&&bound| ratio >= bound)
+                .count();
+            buckets[bucket].add(*size);
+
+            let folder = item.original_parent.to_string_lossy().into_owned();
+            folders.entry(folder).or_default().add(*size);
+            let extension = Path::new(&item.name)
+                .extension()
+                .map(|extension| format!(".{}", extension.to_string_lossy().to_lowercase()))
+                .unwrap_or_else(|| tr("dashboard.no_extension").to_owned());
+            extensions.entry(extension).or_default().add(*size);
+        }
+
+        let mut thresholds = SAVING_THRESHOLDS.to_vec();
+        if !thresholds.contains(&retention) {
+            thresholds.push(retention);
+        }
+        let mut savings: Vec<Saving> = thresholds
+            .into_iter()
+            .map(|retention| {
+                let cutoff = retention.cutoff(taken_at).map(|cutoff| cutoff.timestamp());
+                let mut freed = SizeGroup::default();
+                for (item, size) in &snapshot.items {
+                    if cutoff.map_or(false, |cutoff| item.time_deleted < cutoff)
+                        && protecting_rule(rules, item).is_none()
+                    {
+                        freed.add(*size);
+                    }
+                }
+                Saving {
+                    retention,
+                    count: freed.count,
+                    size: freed.size,
+                }
+            })
+            .collect();
+        // Une date limite plus récente correspond à une durée plus courte.
+        savings.sort_by_key(|saving| std::cmp::Reverse(saving.retention.cutoff(taken_at)));
+
+        Self {
+            retention,
+            rules: rules.to_vec(),
+            total,
+            unknown_size,
+            protected,
+            oldest: snapshot
+                .items
+                .iter()
+                .map(|(item, _)| item.time_deleted)
+                .min(),
+            buckets,
+            folders: top_groups(folders),
+            extensions: top_groups(extensions),
+            savings,
+        }
+    }
+
+    fn is_computed_for(&self, retention: Retention, rules: &[ProtectionRule]) -> bool {
+        self.retention == retention && self.rules == rules
+    }
+}
+
+/// Groupes les plus volumineux, nommés d'après leur clé.
+fn top_groups(groups: HashMap<String, SizeGroup>) -> Vec<SizeGroup> {
+    let mut groups: Vec<SizeGroup> = groups
+        .into_iter()
+        .map(|(name, group)| SizeGroup { name, ..group })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.size
+            .cmp(&a.size)
+            .then(b.count.cmp(&a.count))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    groups.truncate(TOP_GROUPS);
+    groups
+}
+
+/// Tableau de bord de la corbeille: dernier relevé et statistiques, recalculées
+/// quand la politique de rétention change.
+#[derive(Default)]
+pub struct Dashboard {
+    snapshot: Option<TrashSnapshot>,
+    stats: Option<TrashStats>,
+}
+
+impl Dashboard {
+    pub fn has_snapshot(&self) -> bool {
+        self.snapshot.is_some()
+    }
+
+    pub fn set_snapshot(&mut self, snapshot: TrashSnapshot) {
+        self.snapshot = Some(snapshot);
+        self.stats = None;
+    }
+
+    /// Affiche le tableau de bord. Retourne `true` si un nouveau relevé est demandé.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        retention: Retention,
+        rules: &[ProtectionRule],
+        busy: bool,
+    ) -> bool {
+        let mut refresh = false;
+        ui.horizontal(|ui| {
+            refresh = ui
+                .add_enabled(!busy, egui::Button::new(tr("dashboard.refresh")))
+                .clicked();
+            if let Some(snapshot) = &self.snapshot {
+                ui.label(
+                    egui::RichText::new(trf(
+                        "dashboard.taken_at",
+                        &[&format_date(snapshot.taken_at)],
+                    ))
+                    .weak(),
+                );
+            }
+        });
+        ui.separator();
+
+        let Some(snapshot) = &self.snapshot else {
+            ui.label(tr("dashboard.empty"));
+            return refresh;
+        };
+        if !self
+            .stats
+            .as_ref()
+            .map_or(false, |stats| stats.is_computed_for(retention, rules))
+        {
+            self.stats = Some(TrashStats::compute(snapshot, retention, rules));
+        }
+        let Some(stats) = &self.stats else {
+            return refresh;
+        };
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                // *** SUMMARY ***
+                egui::Grid::new("dashboard_summary")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        let mut row = |label: &str, value: String| {
+                            ui.label(egui::RichText::new(label).strong());
+                            ui.label(value);
+                            ui.end_row();
+                        };
+                        row(tr("dashboard.items"), stats.total.count.to_string());
+                        let mut size = format_size(Some(stats.total.size));
+                        if stats.unknown_size > 0 {
+                            size.push(' ');
+                            size.push_str(&trf("dashboard.unknown_size", &[&stats.unknown_size]));
+                        }
+                        row(tr("dashboard.total_size"), size);
+                        row(tr("dashboard.protected"), stats.protected.to_string());
+                        if let Some(oldest) = stats.oldest {
+                            row(tr("dashboard.oldest"), format_date(oldest));
+                        }
+                    });
+
+                // *** AGE HISTOGRAM ***
+                ui.add_space(8.0);
+                ui.heading(tr("dashboard.age_title"));
+                ui.label(trf("dashboard.age_axis", &[&retention]));
+                age_histogram(ui, stats);
+
+                // *** TOP FOLDERS AND EXTENSIONS ***
+                ui.add_space(8.0);
+                ui.columns(2, |columns| {
+                    columns[0].heading(tr("dashboard.folders_title"));
+                    size_ranking(&mut columns[0], "dashboard_folders", &stats.folders, stats);
+                    columns[1].heading(tr("dashboard.extensions_title"));
+                    size_ranking(
+                        &mut columns[1],
+                        "dashboard_extensions",
+                        &stats.extensions,
+                        stats,
+                    );
+                });
+
+                // *** SAVINGS BY THRESHOLD ***
+                ui.add_space(8.0);
+                ui.heading(tr("dashboard.savings_title"));
+                egui::Grid::new("dashboard_savings")
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for saving in &stats.savings {
+                            let mut label = egui::RichText::new(saving.retention.to_string());
+                            if saving.retention == retention {
+                                label = egui::RichText::new(trf(
+                                    "dashboard.current_threshold",
+                                    &[&saving.retention],
+                                ))
+                                .strong();
+                            }
+                            ui.label(label);
+                            ui.label(trf("dashboard.count", &[&saving.count]));
+                            ui.label(format_size(Some(saving.size)));
+                            ui.add(
+                                egui::ProgressBar::new(share(saving.size, stats.total.size))
+                                    .desired_width(200.0),
+                            );
+                            ui.end_row();
+                        }
+                    });
+            });
+        refresh
+    }
+}
+
+/// Part de `size` dans `total`, pour les barres de proportion.
+fn share(size: u64, total: u64) -> f32 {
+    if total == 0 {
+        0.0
+    } else {
+        size as f32 / total as f32
+    }
+}
+
+/// Histogramme du nombre d'éléments par tranche d'âge. Les tranches au-delà
+/// de la durée de rétention sont de la couleur des avertissements.
+fn age_histogram(ui: &mut egui::Ui, stats: &TrashStats) {
+    let kept_color = ui.visuals().selection.bg_fill;
+    let expired_color = ui.visuals().warn_fg_color;
+    let bars = stats
+        .buckets
+        .iter()
+        .enumerate()
+        .map(|(index, bucket)| {
+            let color = if index >= FIRST_EXPIRED_BUCKET {
+                expired_color
+            } else {
+                kept_color
+            };
+            Bar::new(index as f64, bucket.count as f64)
+                .name(AGE_LABELS[index])
+                .fill(color)
+        })
+        .collect();
+    let sizes: Vec<u64> = stats.buckets.iter().map(|bucket| bucket.size).collect();
+    let chart = BarChart::new(bars)
+        .width(0.8)
+        .element_formatter(Box::new(move |bar, _| {
+            format!(
+                "{}\n{}\n{}",
+                bar.name,
+                trf("dashboard.count", &[&bar.value]),
+                format_size(sizes.get(bar.argument as usize).copied())
+            )
+        }));
+
+    Plot::new("dashboard_age_histogram")
+        .height(180.0)
+        .allow_zoom(false)
+        .allow_drag(false)
+        .allow_scroll(false)
+        .allow_boxed_zoom(false)
+        .allow_double_click_reset(false)
+        .show_x(false)
+        .show_y(false)
+        .include_y(0.0)
+        .x_axis_formatter(|x, _| {
+            let index = x.round();
+            if (x - index).abs() < f64::EPSILON && (0.0..AGE_LABELS.len() as f64).contains(&index) {
+                AGE_LABELS[index as usize].to_owned()
+            } else {
+                String::new()
+            }
+        })
+        .y_axis_formatter(|y, _| {
+            if y >= 0.0 && y.fract() == 0.0 {
+                format!("{}", y)
+            } else {
+                String::new()
+            }
+        })
+        .show(ui, |plot_ui| plot_ui.bar_chart(chart));
+}
+
+/// Classement des groupes les plus volumineux, avec leur part de la corbeille.
+fn size_ranking(ui: &mut egui::Ui, id: &str, groups: &[SizeGroup], stats: &TrashStats) {
+    egui::Grid::new(id)
+        .num_columns(4)
+        .striped(true)
+        .show(ui, |ui| {
+            for group in groups {
+                ui.label(&group.name).on_hover_text(&group.name);
+                ui.label(trf("dashboard.count", &[&group.count]));
+                ui.label(format_size(Some(group.size)));
+                ui.add(
+                    egui::ProgressBar::new(share(group.size, stats.total.size)).desired_width(80.0),
+                );
+                ui.end_row();
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::*;
+
+    const TAKEN_AT: i64 = 1_700_000_000;
+    const DAY: i64 = 24 * 60 * 60;
+
+    /// Élément mis à la corbeille `age` secondes avant le relevé.
+    fn item(name: &str, parent: &str, age: i64) -> TrashItem {
+        TrashItem {
+            id: OsString::from(name),
+            name: name.to_owned(),
+            original_parent: parent.into(),
+            time_deleted: TAKEN_AT - age,
+        }
+    }
+
+    fn snapshot(items: Vec<(TrashItem, Option<u64>)>) -> TrashSnapshot {
+        TrashSnapshot {
+            items,
+            taken_at: TAKEN_AT,
+        }
+    }
+
+    fn psd() -> Vec<ProtectionRule> {
+        vec![ProtectionRule::Extension("psd".to_owned())]
+    }
+
+    #[test]
+    fn buckets_start_at_their_lower_bound() {
+        // Rétention de 8 jours: bornes à 2, 4, 8, 16 et 32 jours.
+        let snapshot = snapshot(
+            [
+                DAY,
+                2 * DAY,
+                4 * DAY,
+                8 * DAY - 1,
+                8 * DAY,
+                16 * DAY,
+                32 * DAY,
+            ]
+            .iter()
+            .enumerate()
+            .map(|(index, &age)| (item(&format!("{index}.txt"), "/tmp", age), Some(1)))
+            .collect(),
+        );
+
+        let stats = TrashStats::compute(&snapshot, Retention::days(8), &[]);
+
+        let counts: Vec<usize> = stats.buckets.iter().map(|bucket| bucket.count).collect();
+        assert_eq!(counts, [1, 1, 2, 1, 1, 1]);
+        assert_eq!(stats.oldest, Some(TAKEN_AT - 32 * DAY));
+    }
+
+    #[test]
+    fn protected_items_are_counted_but_never_freed() {
+        let snapshot = snapshot(vec![
+            (item("ancien.psd", "/tmp", 40 * DAY), Some(100)),
+            (item("ancien.txt", "/tmp", 40 * DAY), Some(10)),
+            (item("dossier", "/tmp", 40 * DAY), None),
+            (item("récent.txt", "/tmp", DAY), Some(5)),
+        ]);
+
+        let stats = TrashStats::compute(&snapshot, Retention::days(8), &psd());
+
+        assert_eq!((stats.total.count, stats.total.size), (4, 115));
+        assert_eq!(stats.protected, 1);
+        assert_eq!(stats.unknown_size, 1);
+        assert_eq!((stats.buckets[5].count, stats.buckets[5].size), (3, 110));
+        let saving = stats
+            .savings
+            .iter()
+            .find(|saving| saving.retention == Retention::days(8))
+            .unwrap();
+        assert_eq!((saving.count, saving.size), (2, 10));
+    }
+
+    #[test]
+    fn savings_include_the_current_retention_in_order() {
+        let snapshot = snapshot(vec![
+            (item("15-jours.txt", "/tmp", 15 * DAY), Some(10)),
+            (item("3-jours.txt", "/tmp", 3 * DAY), Some(1)),
+        ]);
+
+        let stats = TrashStats::compute(&snapshot, Retention::days(10), &[]);
+
+        let savings: Vec<(Retention, usize)> = stats
+            .savings
+            .iter()
+            .map(|saving| (saving.retention, saving.count))
+            .collect();
+        let months = |amount| Retention {
+            amount,
+            unit: TimeUnit::Months,
+        };
+        let week = Retention {
+            amount: 1,
+            unit: TimeUnit::Weeks,
+        };
+        assert_eq!(
+            savings,
+            [
+                (week, 1),
+                (Retention::days(10), 1),
+                (months(1), 0),
+                (months(3), 0),
+                (months(6), 0),
+                (months(12), 0),
+            ]
+        );
+
+        // Une durée déjà comparée n'est pas ajoutée une seconde fois.
+        let stats = TrashStats::compute(&snapshot, week, &[]);
+        assert_eq!(stats.savings.len(), SAVING_THRESHOLDS.len());
+    }
+
+    #[test]
+    fn groups_are_sorted_by_size_then_count_then_name() {
+        let snapshot = snapshot(vec![
+            (item("a1.txt", "/a", DAY), Some(10)),
+            (item("a2.TXT", "/a", DAY), Some(10)),
+            (item("b.log", "/b", DAY), Some(20)),
+            (item("c", "/c", DAY), Some(30)),
+            (item("d.log", "/d", DAY), Some(20)),
+        ]);
+
+        let stats = TrashStats::compute(&snapshot, Retention::days(8), &[]);
+
+        let names = |groups: &[SizeGroup]| -> Vec<(String, usize, u64)> {
+            groups
+                .iter()
+                .map(|group| (group.name.clone(), group.count, group.size))
+                .collect()
+        };
+        assert_eq!(
+            names(&stats.folders),
+            [
+                ("/c".to_owned(), 1, 30),
+                ("/a".to_owned(), 2, 20),
+                ("/b".to_owned(), 1, 20),
+                ("/d".to_owned(), 1, 20),
+            ]
+        );
+        assert_eq!(
+            names(&stats.extensions),
+            [
+                (".log".to_owned(), 2, 40),
+                (tr("dashboard.no_extension").to_owned(), 1, 30),
+                (".txt".to_owned(), 2, 20),
+            ]
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::thread::{self, ThreadId};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::console::{Category, ConsoleContent, ConsoleEntry, Level};
+
+/// Événements reçus par la couche et pas encore affichés dans la console.
+static PENDING: Mutex<Vec<ConsoleEntry>> = Mutex::new(Vec::new());
+
+/// Niveau minimal des événements recopiés dans la console.
+static THRESHOLD: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+/// Thread de l'interface et contexte à redessiner quand un événement arrive
+/// depuis un autre thread.
+static INTERFACE: Mutex<Option<(ThreadId, egui::Context)>> = Mutex::new(None);
+
+pub fn set_trace_level(level: Level) {
+    THRESHOLD.store(level as u8, Ordering::Relaxed);
+}
+
+/// Enregistre le thread courant comme celui de l'interface.
+pub fn set_repaint_context(ctx: &egui::Context) {
+    *INTERFACE.lock().unwrap_or_else(PoisonError::into_inner) =
+        Some((thread::current().id(), ctx.clone()));
+}
+
+/// Retire les événements en attente, du plus ancien au plus récent.
+pub fn take_traces() -> Vec<ConsoleEntry> {
+    std::mem::take(&mut *PENDING.lock().unwrap_or_else(PoisonError::into_inner))
+}
+
+/// Couche `tracing` qui recopie les événements dans la console de l'interface,
+/// précédés des spans en cours (`purge{items=3} › batch: …`).
+pub struct ConsoleLayer;
+
+/// Champs d'un span, mis en forme à sa création.
+struct SpanFields(String);
+
+impl<S> Layer<S> for ConsoleLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(visitor.fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let level = match *event.metadata().level() {
+            tracing::Level::ERROR => Level::Error,
+            tracing::Level::WARN => Level::Warning,
+            tracing::Level::INFO => Level::Info,
+            _ => Level::Debug,
+        };
+        if (level as u8) < THRESHOLD.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let mut text = String::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                let _ = write!(text, "{}", span.name());
+                if let Some(SpanFields(fields)) = span.extensions().get::<SpanFields>() {
+                    if !fields.is_empty() {
+                        let _ = write!(text, "{{{}}}", fields);
+                    }
+                }
+                text.push_str(" › ");
+            }
+        }
+        if text.is_empty() {
+            let _ = write!(text, "{}: ", event.metadata().target());
+        } else {
+            text.truncate(text.len() - " › ".len());
+            text.push_str(": ");
+        }
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        text.push_str(&visitor.message);
+        if !visitor.fields.is_empty() {
+            let _ = write!(text, " ({})", visitor.fields);
+        }
+
+        PENDING
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(ConsoleEntry::new(
+                level,
+                Category::Diagnostics,
+                ConsoleContent::Message(text),
+            ));
+        // Sur le thread de l'interface, le contexte peut être verrouillé:
+        // l'événement sera affiché à la prochaine image.
+        if let Some((interface, ctx)) = &*INTERFACE.lock().unwrap_or_else(PoisonError::into_inner) {
+            if *interface != thread::current().id() {
+                ctx.request_repaint();
+            }
+        }
+    }
+}
+
+/// Sépare le message d'un événement de ses autres champs (`nom=valeur`).
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: String,
+}
+
+impl FieldVisitor {
+    fn push_field(&mut self, field: &Field, value: fmt::Arguments) {
+        if field.name() == "message" {
+            let _ = self.message.write_fmt(value);
+            return;
+        }
+        if !self.fields.is_empty() {
+            self.fields.push_str(", ");
+        }
+        let _ = write!(self.fields, "{}={}", field.name(), value);
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push_field(field, format_args!("{}", value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push_field(field, format_args!("{:?}", value));
+    }
+}
@@ -0,0 +1,183 @@
+use std::fmt::Write;
+use std::sync::{PoisonError, RwLock};
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Local, SecondsFormat, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::consts;
+use crate::i18n::{tr, trf};
+
+/// Présentation des dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateStyle {
+    /// ISO 8601 (`2026-01-01T10:00:00+01:00`).
+    Iso8601,
+    /// Convention de la langue de l'interface.
+    Locale,
+    /// Format strftime choisi par l'utilisateur.
+    Custom,
+}
+
+impl DateStyle {
+    pub const ALL: [DateStyle; 3] = [DateStyle::Iso8601, DateStyle::Locale, DateStyle::Custom];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DateStyle::Iso8601 => "ISO 8601",
+            DateStyle::Locale => tr("date_style.locale"),
+            DateStyle::Custom => tr("date_style.custom"),
+        }
+    }
+}
+
+/// Réglages d'affichage des dates, communs aux tableaux, à la console et aux exports.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DateSettings {
+    pub style: DateStyle,
+    /// Format strftime du style personnalisé.
+    pub custom: String,
+    /// Affiche les dates en UTC plutôt qu'en heure locale.
+    pub utc: bool,
+    /// Affiche l'écart avec maintenant (« il y a 3 jours ») plutôt que la
+    /// date. Les exports gardent toujours la date complète.
+    pub relative: bool,
+}
+
+impl Default for DateSettings {
+    fn default() -> Self {
+        Self {
+            style: DateStyle::Locale,
+            custom: "%Y-%m-%d %H:%M".to_owned(),
+            utc: false,
+            relative: false,
+        }
+    }
+}
+
+impl DateSettings {
+    /// Indique si le format personnalisé est un format strftime valide.
+    pub fn is_valid_custom(&self) -> bool {
+        is_valid_strftime(&self.custom)
+    }
+
+    fn pattern(&self) -> &str {
+        match self.style {
+            DateStyle::Custom if self.is_valid_custom() => &self.custom,
+            // Un format personnalisé invalide retombe sur le style de la langue.
+            _ => tr("date.locale_format"),
+        }
+    }
+
+    fn render<Tz: TimeZone>(&self, datetime: DateTime<Tz>) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        if self.style == DateStyle::Iso8601 {
+            return datetime.to_rfc3339_opts(SecondsFormat::Secs, self.utc);
+        }
+        let mut text = String::new();
+        let _ = write!(text, "{}", datetime.format(self.pattern()));
+        text
+    }
+
+    fn absolute(&self, timestamp: i64) -> String {
+        match DateTime::from_timestamp(timestamp, 0) {
+            Some(datetime) if self.utc => self.render(datetime.with_timezone(&Utc)),
+            Some(datetime) => self.render(datetime.with_timezone(&Local)),
+            None => String::new(),
+        }
+    }
+}
+
+/// Indique si `pattern` ne contient que des spécificateurs strftime connus.
+pub fn is_valid_strftime(pattern: &str) -> bool {
+    !StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error))
+}
+
+/// Réglages en vigueur, partagés avec les opérations en arrière-plan.
+/// `None` tant qu'ils n'ont pas été définis: les réglages par défaut s'appliquent.
+static DATE_SETTINGS: RwLock<Option<DateSettings>> = RwLock::new(None);
+
+pub fn set_date_settings(settings: &DateSettings) {
+    let mut current = DATE_SETTINGS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    if current.as_ref() != Some(settings) {
+        *current = Some(settings.clone());
+    }
+}
+
+fn with_date_settings<R>(f: impl FnOnce(&DateSettings) -> R) -> R {
+    let current = DATE_SETTINGS.read().unwrap_or_else(PoisonError::into_inner);
+    match current.as_ref() {
+        Some(settings) => f(settings),
+        None => f(&DateSettings::default()),
+    }
+}
+
+/// Date affichée dans les tableaux et la console, vide si l'horodatage est invalide.
+pub fn format_date(timestamp: i64) -> String {
+    with_date_settings(|settings| {
+        if settings.relative {
+            format_relative(timestamp)
+        } else {
+            settings.absolute(timestamp)
+        }
+    })
+}
+
+/// Date complète, jamais relative, pour les exports.
+pub fn format_date_absolute(timestamp: i64) -> String {
+    with_date_settings(|settings| settings.absolute(timestamp))
+}
+
+/// Date au format RFC 3339 en heure locale, indépendante des réglages,
+/// pour le journal d'audit.
+pub fn rfc3339(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|datetime| datetime.with_timezone(&Local).to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// Écart avec maintenant: « il y a 3 jours », « dans 2 h »…
+fn format_relative(timestamp: i64) -> String {
+    let seconds = Local::now().timestamp() - timestamp;
+    let future = seconds < 0;
+    let seconds = seconds.abs();
+    let (count, key) = match seconds {
+        0..=59 => return tr("relative.now").to_owned(),
+        60..=3_599 => (
+            seconds / 60,
+            ["relative.minutes_ago", "relative.minutes_in"],
+        ),
+        3_600..=86_399 => (seconds / 3_600, ["relative.hours_ago", "relative.hours_in"]),
+        86_400..=172_799 => (1, ["relative.day_ago", "relative.day_in"]),
+        _ => (seconds / 86_400, ["relative.days_ago", "relative.days_in"]),
+    };
+    trf(key[usize::from(future)], &[&count])
+}
+
+/// Nombre de jours écoulés depuis la mise à la corbeille.
+pub fn age_in_days(time_deleted: i64) -> i64 {
+    (Local::now().timestamp() - time_deleted) / 86_400
+}
+
+/// Taille lisible (Ko, Mo, Go ou KB, MB, GB), vide si elle est inconnue.
+pub fn format_size(size: Option<u64>) -> String {
+    let Some(size) = size else {
+        return String::new();
+    };
+    let size = size as f64;
+    if size >= consts::BYTES_PER_GB {
+        format!("{:.2} {}", size / consts::BYTES_PER_GB, tr("unit.gb"))
+    } else if size >= 1024.0 * 1024.0 {
+        format!("{:.1} {}", size / (1024.0 * 1024.0), tr("unit.mb"))
+    } else if size >= 1024.0 {
+        format!("{:.0} {}", size / 1024.0, tr("unit.kb"))
+    } else {
+        format!("{} {}", size, tr("unit.bytes"))
+    }
+}
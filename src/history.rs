@@ -0,0 +1,135 @@
+use chrono::DateTime;
+
+use crate::audit::{AuditEntry, AuditItem, AuditLog};
+use crate::format::{format_date, format_size};
+use crate::i18n::{tr, trf};
+use crate::table::status_label;
+
+/// État de la fenêtre d'historique des suppressions.
+#[derive(Default)]
+pub struct HistoryDialog {
+    open: bool,
+    entries: Vec<AuditEntry>,
+    search: String,
+    error: Option<String>,
+}
+
+impl HistoryDialog {
+    /// Ouvre la fenêtre en relisant le journal d'audit.
+    pub fn open(&mut self, audit_log: Option<&AuditLog>) {
+        self.open = true;
+        self.reload(audit_log);
+    }
+
+    fn reload(&mut self, audit_log: Option<&AuditLog>) {
+        self.entries.clear();
+        match audit_log.map(AuditLog::read) {
+            Some(Ok(entries)) => {
+                self.entries = entries;
+                self.error = None;
+            }
+            Some(Err(e)) => self.error = Some(e.to_string()),
+            None => self.error = Some(tr("data_dir.missing").to_owned()),
+        }
+    }
+}
+
+/// Date RFC 3339 du journal, affichée comme les autres dates.
+fn format_rfc3339(date: &str) -> String {
+    DateTime::parse_from_rfc3339(date)
+        .map(|datetime| format_date(datetime.timestamp()))
+        .unwrap_or_else(|_| date.to_owned())
+}
+
+fn matches_search(item: &AuditItem, search: &str) -> bool {
+    search.is_empty()
+        || item.name.to_lowercase().contains(search)
+        || item.original_path.to_lowercase().contains(search)
+}
+
+pub fn history_window(
+    ctx: &egui::Context,
+    dialog: &mut HistoryDialog,
+    audit_log: Option<&AuditLog>,
+) {
+    let mut open = dialog.open;
+    let mut reload = false;
+    egui::Window::new(tr("history.title"))
+        .open(&mut open)
+        .default_width(620.0)
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(tr("history.search"));
+                ui.text_edit_singleline(&mut dialog.search);
+                reload = ui.button(tr("history.reload")).clicked();
+            });
+            if let Some(audit_log) = audit_log {
+                ui.label(egui::RichText::new(audit_log.path().display().to_string()).weak());
+            }
+            if let Some(error) = &dialog.error {
+                ui.colored_label(
+                    ui.visuals().error_fg_color,
+                    trf("history.read_error", &[error]),
+                );
+            }
+            ui.separator();
+
+            let search = dialog.search.trim().to_lowercase();
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    if dialog.entries.is_empty() {
+                        ui.label(tr("history.empty"));
+                    }
+                    // Les exécutions les plus récentes d'abord.
+                    for (index, entry) in dialog.entries.iter().enumerate().rev() {
+                        let items: Vec<&AuditItem> = entry
+                            .items
+                            .iter()
+                            .filter(|item| matches_search(item, &search))
+                            .collect();
+                        if !search.is_empty() && items.is_empty() {
+                            continue;
+                        }
+                        let mut title = trf(
+                            "history.entry",
+                            &[
+                                &format_rfc3339(&entry.timestamp),
+                                &entry.trigger.label(),
+                                &entry.purged_count(),
+                                &format_size(Some(entry.purged_size())),
+                            ],
+                        );
+                        let quarantined = entry.quarantined_count();
+                        if quarantined > 0 {
+                            title.push_str(&trf("history.entry_quarantined", &[&quarantined]));
+                        }
+                        egui::CollapsingHeader::new(title)
+                            .id_source(("audit_entry", index))
+                            .show(ui, |ui| {
+                                ui.label(trf("history.policy", &[&entry.policy.describe()]));
+                                egui::Grid::new(("audit_items", index))
+                                    .num_columns(5)
+                                    .striped(true)
+                                    .show(ui, |ui| {
+                                        for item in items {
+                                            let status = ui.label(status_label(item.status));
+                                            if let Some(error) = &item.error {
+                                                status.on_hover_text(error);
+                                            }
+                                            ui.label(&item.name);
+                                            ui.label(&item.original_path);
+                                            ui.label(format_rfc3339(&item.time_deleted));
+                                            ui.label(format_size(item.size));
+                                            ui.end_row();
+                                        }
+                                    });
+                            });
+                    }
+                });
+        });
+    dialog.open = open;
+    if reload {
+        dialog.reload(audit_log);
+    }
+}
@@ -0,0 +1,681 @@
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+/// Langue de l'interface, des messages de la console et de la ligne de commande.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[serde(rename = "fr")]
+    French,
+    #[serde(rename = "en")]
+    English,
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Self::from_system()
+    }
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::French, Language::English];
+
+    /// Nom de la langue dans cette langue, pour le sélecteur.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::French => "Français",
+            Language::English => "English",
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::French => "fr",
+            Language::English => "en",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|language| language.code().eq_ignore_ascii_case(code))
+    }
+
+    /// Langue de la locale du système (`fr-CH`, `en_US.UTF-8`…). Sans locale
+    /// connue, l'application reste en français.
+    pub fn from_system() -> Self {
+        match sys_locale::get_locale() {
+            Some(locale) if !locale.to_lowercase().starts_with("fr") => Language::English,
+            _ => Language::French,
+        }
+    }
+}
+
+/// Langue courante, partagée avec les opérations en arrière-plan.
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        0 => Language::French,
+        _ => Language::English,
+    }
+}
+
+/// Texte du catalogue dans la langue courante. Une clé absente du catalogue
+/// est retournée telle quelle.
+pub fn tr(key: &'static str) -> &'static str {
+    match lookup(key) {
+        Some((french, english)) => match language() {
+            Language::French => french,
+            Language::English => english,
+        },
+        None => key,
+    }
+}
+
+/// Comme [`tr`], en remplaçant chaque `{}` du texte par l'argument suivant.
+pub fn trf(key: &'static str, args: &[&dyn Display]) -> String {
+    let mut args = args.iter();
+    let mut parts = tr(key).split("{}");
+    let mut text = parts.next().unwrap_or_default().to_owned();
+    for part in parts {
+        if let Some(arg) = args.next() {
+            text.push_str(&arg.to_string());
+        }
+        text.push_str(part);
+    }
+    text
+}
+
+/// Déclare le catalogue: pour chaque clé, le texte français puis anglais.
+macro_rules! catalog {
+    ($($key:literal => $french:expr, $english:expr;)*) => {
+        fn lookup(key: &str) -> Option<(&'static str, &'static str)> {
+            match key {
+                $($key => Some(($french, $english)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+catalog! {
+    // *** UNITÉS ***
+    "unit.bytes" => "o", "B";
+    "unit.kb" => "Ko", "KB";
+    "unit.mb" => "Mo", "MB";
+    "unit.gb" => "Go", "GB";
+    "suffix.days" => " jours", " days";
+    "suffix.items" => " éléments", " items";
+    "suffix.gb" => " Go", " GB";
+
+    // *** DATES ***
+    "date.locale_format" => "%d.%m.%Y %H:%M", "%d %b %Y %H:%M";
+    "date_style.locale" => "Style régional", "Regional style";
+    "date_style.custom" => "Personnalisé", "Custom";
+    "time_unit.minutes" => "minutes", "minutes";
+    "time_unit.hours" => "heures", "hours";
+    "time_unit.days" => "jours", "days";
+    "time_unit.weeks" => "semaines", "weeks";
+    "time_unit.months" => "mois", "months";
+    "retention.minutes" => "{} min", "{} min";
+    "retention.hours" => "{} h", "{} h";
+    "retention.day" => "{} jour", "{} day";
+    "retention.days" => "{} jours", "{} days";
+    "retention.week" => "{} semaine", "{} week";
+    "retention.weeks" => "{} semaines", "{} weeks";
+    "retention.month" => "{} mois", "{} month";
+    "retention.months" => "{} mois", "{} months";
+    "relative.now" => "à l'instant", "just now";
+    "relative.minutes_ago" => "il y a {} min", "{} min ago";
+    "relative.minutes_in" => "dans {} min", "in {} min";
+    "relative.hours_ago" => "il y a {} h", "{} h ago";
+    "relative.hours_in" => "dans {} h", "in {} h";
+    "relative.day_ago" => "il y a {} jour", "{} day ago";
+    "relative.day_in" => "dans {} jour", "in {} day";
+    "relative.days_ago" => "il y a {} jours", "{} days ago";
+    "relative.days_in" => "dans {} jours", "in {} days";
+
+    // *** MENU ***
+    "menu.file" => "Fichier", "File";
+    "menu.export" => "Exporter…", "Export…";
+    "menu.export_console" => "Exporter la console…", "Export console…";
+    "menu.import_config" => "Importer la configuration…", "Import configuration…";
+    "menu.export_config" => "Exporter la configuration…", "Export configuration…";
+    "menu.history" => "Historique…", "History…";
+    "menu.quarantine" => "Quarantaine…", "Quarantine…";
+    "menu.settings" => "Paramètres…", "Settings…";
+    "menu.quit" => "Quitter", "Quit";
+
+    // *** PANNEAU LATÉRAL ***
+    "side.profile" => "Profil", "Profile";
+    "side.profiles" => "Gérer les profils…", "Manage profiles…";
+    "side.keep" => "Préserver", "Keep";
+    "side.size_quota" => "Taille max.", "Max. size";
+    "side.protections" => "Protections ({})", "Protections ({})";
+    "side.analyze" => "Analyser", "Analyze";
+    "side.purge" => "SUPPRIMER", "DELETE";
+    "side.source_code" => "Code source", "Source code";
+
+    // *** OPÉRATIONS EN ARRIÈRE-PLAN ***
+    "job.analysis" => "Analyse", "Analysis";
+    "job.purge" => "Suppression", "Deletion";
+    "job.restore" => "Restauration", "Restore";
+    "job.comparison" => "Comparaison", "Comparison";
+    "job.dashboard" => "Relevé de la corbeille", "Reading the trash";
+    "job.quarantine_expiry" => "Quarantaine expirée", "Expired quarantine";
+    "job.failed" => "**** L'opération s'est arrêtée sans résultat ****\n",
+        "**** The operation stopped without a result ****\n";
+    "button.cancel" => "Annuler", "Cancel";
+
+    // *** TABLEAU DES ÉLÉMENTS ***
+    "details.title" => "Détails", "Details";
+    "details.original_path" => "Chemin d'origine", "Original path";
+    "details.age_days" => "{} jours", "{} days";
+    "details.error" => "Erreur", "Error";
+    "details.id" => "Identifiant", "Identifier";
+    "table.age_days" => "{} j", "{} d";
+    "toolbar.check_all" => "Tout cocher", "Check all";
+    "toolbar.uncheck_all" => "Tout décocher", "Uncheck all";
+    "toolbar.invert" => "Inverser", "Invert";
+    "toolbar.purge_selection" => "Supprimer la sélection ({})", "Delete selection ({})";
+    "toolbar.restore_selection" => "Restaurer la sélection ({})", "Restore selection ({})";
+    "toolbar.failed_count" => "Éléments en échec: {}", "Failed items: {}";
+    "toolbar.retry" => "Réessayer les éléments en échec", "Retry failed items";
+
+    // *** COLONNES ***
+    "column.name" => "Nom", "Name";
+    "column.item_name" => "Nom de l'élément", "Item name";
+    "column.file_name" => "Nom du fichier", "File name";
+    "column.original_folder" => "Dossier d'origine", "Original folder";
+    "column.deletion_date" => "Date de suppression", "Deletion date";
+    "column.trashed_date" => "Date de mise à la corbeille", "Date moved to trash";
+    "column.age" => "Âge", "Age";
+    "column.size" => "Taille", "Size";
+    "column.status" => "Statut", "Status";
+    "column.rule" => "Règle", "Rule";
+    "column.protected_by" => "Protégé par", "Protected by";
+    "column.cause" => "Cause", "Cause";
+    "column.detail" => "Détail", "Detail";
+    "column.count" => "Nombre", "Count";
+    "column.profile" => "Profil", "Profile";
+    "column.selected_by" => "Sélectionné par", "Selected by";
+
+    // *** STATUTS ***
+    "status.selected" => "À supprimer", "To delete";
+    "status.purged" => "Supprimé", "Deleted";
+    "status.restored" => "Restauré", "Restored";
+    "status.quarantined" => "En quarantaine", "Quarantined";
+    "status.failed" => "Échec", "Failed";
+    "status.protected" => "Protégé", "Protected";
+    "status.ok" => "OK", "OK";
+    "status.oops" => "Oups !", "Oops!";
+    "selection.age" => "Âge", "Age";
+    "selection.size_quota" => "Taille max.", "Max. size";
+    "error_kind.not_found" => "Introuvable", "Not found";
+    "error_kind.permission_denied" => "Accès refusé", "Access denied";
+    "error_kind.in_use" => "Fichier utilisé", "File in use";
+    "error_kind.name_collision" => "Conflit de nom", "Name conflict";
+    "error_kind.other" => "Autre erreur", "Other error";
+    "error.already_exists" => "Un élément existe déjà: {}", "An item already exists: {}";
+    "error.quarantine_source" =>
+        "Copié en quarantaine, mais {} n'a pas pu être entièrement supprimé: {}",
+        "Copied to the quarantine, but {} could not be fully removed: {}";
+    "error.not_in_trash" => "L'élément n'est plus dans la corbeille",
+        "The item is no longer in the trash";
+    "error.timestamp" => "**** Erreur lors de la conversion de l'horodatage ****\n",
+        "**** Error while converting the timestamp ****\n";
+    "rule.name_glob" => "Nom (glob)", "Name (glob)";
+    "rule.folder" => "Dossier", "Folder";
+    "rule.extension" => "Extension", "Extension";
+    "rule.invalid_glob" => "Motif glob invalide « {} » (caractère {})",
+        "Invalid glob pattern `{}` (character {})";
+
+    // *** STATISTIQUES ***
+    "stats.success" => "Succès", "Success";
+    "stats.failure" => "Échec", "Failure";
+    "stats.failure_kind" => "Échec: {}", "Failure: {}";
+    "stats.not_processed" => "Non traité (annulé)", "Not processed (cancelled)";
+
+    // *** EXPORT ***
+    "export.title" => "Exporter le rapport", "Export report";
+    "export.format" => "Format", "Format";
+    "export.default_name" => "rapport", "report";
+    "export.file" => "Fichier", "File";
+    "export.save" => "Enregistrer", "Save";
+    "export.overwrite" => "Le fichier {} existe déjà et sera remplacé.",
+        "The file {} already exists and will be replaced.";
+    "export.replace" => "Remplacer", "Replace";
+    "export.done" => "\nRapport exporté dans {}\n", "\nReport exported to {}\n";
+    "export.error" => "**** Erreur lors de l'export du rapport: {} ****\n",
+        "**** Error while exporting the report: {} ****\n";
+
+    // *** ONGLETS ***
+    "tab.items" => "Éléments", "Items";
+    "tab.console" => "Console", "Console";
+    "tab.dashboard" => "Tableau de bord", "Dashboard";
+
+    // *** TABLEAU DE BORD ***
+    "dashboard.refresh" => "Actualiser", "Refresh";
+    "dashboard.taken_at" => "Relevé: {}", "Read: {}";
+    "dashboard.empty" => "La corbeille n'a pas encore été relevée.", "The trash has not been read yet.";
+    "dashboard.items" => "Éléments", "Items";
+    "dashboard.total_size" => "Taille totale", "Total size";
+    "dashboard.unknown_size" => "(taille inconnue pour {} éléments)", "(unknown size for {} items)";
+    "dashboard.protected" => "Éléments protégés", "Protected items";
+    "dashboard.oldest" => "Plus ancien", "Oldest";
+    "dashboard.age_title" => "Âge des éléments", "Item age";
+    "dashboard.age_axis" => "En pourcentage de la durée de rétention ({})",
+        "As a percentage of the retention period ({})";
+    "dashboard.folders_title" => "Dossiers d'origine", "Original folders";
+    "dashboard.extensions_title" => "Extensions", "Extensions";
+    "dashboard.no_extension" => "(sans extension)", "(no extension)";
+    "dashboard.savings_title" => "Espace libéré selon la durée de rétention",
+        "Space freed by retention period";
+    "dashboard.current_threshold" => "{} (actuelle)", "{} (current)";
+    "dashboard.count" => "{} éléments", "{} items";
+
+    // *** CONSOLE ***
+    "console.all_categories" => "Toutes les catégories", "All categories";
+    "console.search" => "Rechercher…", "Search…";
+    "console.copy" => "Copier", "Copy";
+    "console.copy_report" => "Copier le dernier rapport", "Copy last report";
+    "console.copy_report_hint" => "Copie le tableau le plus récent dans le presse-papiers",
+        "Copies the most recent table to the clipboard";
+    "console_format.text" => "Texte", "Text";
+    "console_export.title" => "Exporter la console", "Export console";
+    "console_export.default_name" => "console", "console";
+    "console_export.filtered" => "Seulement les entrées affichées", "Displayed entries only";
+    "console_export.done" => "Console exportée dans {}", "Console exported to {}";
+    "console_export.error" => "**** Erreur lors de l'export de la console: {} ****",
+        "**** Error while exporting the console: {} ****";
+    "level.debug" => "Débogage", "Debug";
+    "level.info" => "Info", "Info";
+    "level.warning" => "Avertissement", "Warning";
+    "level.error" => "Erreur", "Error";
+    "category.general" => "Général", "General";
+    "category.trash" => "Corbeille", "Trash";
+    "category.analysis" => "Analyse", "Analysis";
+    "category.purge" => "Suppression", "Deletion";
+    "category.restore" => "Restauration", "Restore";
+    "category.quarantine" => "Quarantaine", "Quarantine";
+    "category.schedule" => "Planification", "Schedule";
+    "category.export" => "Export", "Export";
+    "category.settings" => "Paramètres", "Settings";
+    "category.diagnostics" => "Diagnostic", "Diagnostics";
+
+    // *** PROFILS ***
+    "profile.none" => "Aucun profil", "No profile";
+    "profile.title" => "Profils", "Profiles";
+    "profile.hint" => "Les modifications de la politique sont enregistrées dans le profil actif.",
+        "Policy changes are saved to the active profile.";
+    "profile.remove" => "Supprimer", "Remove";
+    "profile.name" => "Nom", "Name";
+    "profile.save" => "Enregistrer la politique actuelle", "Save the current policy";
+    "profile.compare_with" => "Comparer « {} » avec", "Compare \"{}\" with";
+    "profile.compare" => "Comparer", "Compare";
+    "profile.switched" => "\nProfil « {} » activé\n", "\nProfile \"{}\" activated\n";
+    "comparison.title" => "COMPARAISON « {} » / « {} »", "COMPARISON \"{}\" / \"{}\"";
+    "comparison.only" => "Seulement « {} »", "Only \"{}\"";
+    "comparison.identical" => "Les deux politiques sélectionnent les mêmes éléments.",
+        "Both policies select the same items.";
+    "comparison.differences" => "Éléments sélectionnés par une seule politique:",
+        "Items selected by only one policy:";
+
+    // *** FICHIER DE CONFIGURATION ***
+    "config.import_title" => "Importer la configuration", "Import configuration";
+    "config.export_title" => "Exporter la configuration", "Export configuration";
+    "config.default_name" => "configuration.toml", "configuration.toml";
+    "config.import" => "Importer", "Import";
+    "config.invalid_value" => "valeur invalide pour « {} »", "invalid value for `{}`";
+    "config.imported" => "\nConfiguration importée depuis {}\n",
+        "\nConfiguration imported from {}\n";
+    "config.exported" => "\nConfiguration exportée dans {}\n", "\nConfiguration exported to {}\n";
+    "config.import_error" => "**** Erreur lors de l'import de la configuration: {} ****\n",
+        "**** Error while importing the configuration: {} ****\n";
+    "config.export_error" => "**** Erreur lors de l'export de la configuration: {} ****\n",
+        "**** Error while exporting the configuration: {} ****\n";
+
+    // *** PARAMÈTRES ***
+    "settings.title" => "Paramètres", "Settings";
+    "settings.language" => "Langue", "Language";
+    "settings.console" => "Console", "Console";
+    "settings.console_entries" => "Entrées conservées", "Entries kept";
+    "settings.trace_level" => "Niveau minimal dans la console", "Minimum level in the console";
+    "settings.dates" => "Dates", "Dates";
+    "settings.date_format" => "Format", "Format";
+    "settings.custom_format" => "Format strftime", "strftime format";
+    "settings.invalid_format" => "Format invalide: le style régional est utilisé.",
+        "Invalid format: the regional style is used.";
+    "settings.utc" => "Afficher les dates en UTC", "Show dates in UTC";
+    "settings.relative" => "Dates relatives (« il y a 3 jours »)", "Relative dates (\"3 days ago\")";
+    "settings.date_preview" => "Aperçu: {}", "Preview: {}";
+    "settings.confirmation" => "Confirmation de suppression", "Deletion confirmation";
+    "settings.confirmation_hint" => "Au-delà de ces seuils, il faut taper un mot pour confirmer.",
+        "Above these thresholds, a word must be typed to confirm.";
+    "settings.item_count" => "Nombre d'éléments", "Number of items";
+    "settings.total_size" => "Taille totale", "Total size";
+    "settings.quarantine_enabled" =>
+        "Placer les éléments en quarantaine au lieu de les supprimer définitivement",
+        "Move items to quarantine instead of deleting them permanently";
+    "settings.duration" => "Durée", "Duration";
+    "settings.schedule_enabled" =>
+        "Appliquer automatiquement la politique pendant que l'application est ouverte",
+        "Apply the policy automatically while the application is open";
+    "settings.time" => "Heure", "Time";
+    "settings.every" => "Tous les", "Every";
+    "settings.max_items" => "Limite d'éléments", "Item limit";
+    "settings.max_size" => "Limite de taille", "Size limit";
+    "settings.limits_hint" => "Une exécution qui dépasse ces limites est ignorée.",
+        "A run that exceeds these limits is skipped.";
+    "settings.last_runs" => "Dernières exécutions:", "Recent runs:";
+
+    // *** CONFIRMATION ***
+    "confirm.title" => "Confirmer la suppression définitive", "Confirm permanent deletion";
+    "confirm.quarantine" => "Les éléments suivants seront placés en quarantaine pendant {} jours:",
+        "The following items will be quarantined for {} days:";
+    "confirm.purge" => "Les éléments suivants seront supprimés définitivement:",
+        "The following items will be permanently deleted:";
+    "confirm.oldest" => "Plus ancien", "Oldest";
+    "confirm.newest" => "Plus récent", "Newest";
+    "confirm.restorable" => "Ils pourront être restaurés depuis Fichier > Quarantaine….",
+        "They can be restored from File > Quarantine….";
+    "confirm.irreversible" => "Cette opération est irréversible.",
+        "This operation cannot be undone.";
+    "confirm.type_word" => "Suppression importante: tapez {} pour confirmer.",
+        "Large deletion: type {} to confirm.";
+    "confirm.word" => "SUPPRIMER", "DELETE";
+    "confirm.purge_button" => "Supprimer définitivement", "Delete permanently";
+
+    // *** PROTECTIONS ***
+    "protection.title" => "Règles de protection", "Protection rules";
+    "protection.hint" => "Les éléments correspondants ne sont jamais supprimés.",
+        "Matching items are never deleted.";
+    "protection.remove" => "Retirer", "Remove";
+    "protection.add" => "Ajouter", "Add";
+
+    // *** CONSOLE ***
+    "analysis.title" => "ANALYSE", "ANALYSIS";
+    "analysis.total" => "Total d'éléments à traiter : {}\n", "Total items to process: {}\n";
+    "analysis.freed" => "Espace libéré : {}\n", "Space freed: {}\n";
+    "analysis.protected" => "Éléments protégés (ignorés) : {}\n",
+        "Protected items (skipped): {}\n";
+    "analysis.protected_title" => "Éléments protégés (ignorés):", "Protected items (skipped):";
+    "purge.nothing" => "Il n'y a aucun élément à supprimer", "There are no items to delete";
+    "purge.title" => "SUPPRESSION DÉFINITIVE", "PERMANENT DELETION";
+    "purge.quarantine_title" => "MISE EN QUARANTAINE", "QUARANTINE";
+    "purge.stats" => "Statistiques de suppression:", "Deletion statistics:";
+    "purge.cancelled" => "**** Suppression annulée: {} éléments non traités ****\n",
+        "**** Deletion cancelled: {} items not processed ****\n";
+    "restore.title" => "RESTAURATION", "RESTORE";
+    "restore.stats" => "Statistiques de restauration:", "Restore statistics:";
+    "restore.cancelled" => "**** Restauration annulée: {} éléments non traités ****\n",
+        "**** Restore cancelled: {} items not processed ****\n";
+    "trash.list_error" =>
+        "**** Erreur lors de la récupération des éléments de la corbeille: {} ****\n",
+        "**** Error while listing the trash items: {} ****\n";
+    "data_dir.missing" => "dossier de données introuvable", "no data folder found";
+    "audit.write_error" => "**** Erreur lors de l'écriture du journal d'audit: {} ****\n",
+        "**** Error while writing the audit log: {} ****\n";
+
+    // *** QUARANTAINE ***
+    "quarantine.title" => "Quarantaine", "Quarantine";
+    "quarantine.hint" =>
+        "Les éléments sont supprimés définitivement {} jours après leur mise en quarantaine.",
+        "Items are permanently deleted {} days after being quarantined.";
+    "quarantine.refresh" => "Actualiser", "Refresh";
+    "quarantine.purge_expired" => "Supprimer les éléments expirés", "Delete expired items";
+    "quarantine.empty" => "La quarantaine est vide.", "The quarantine is empty.";
+    "quarantine.expires" => "Expire le {}", "Expires on {}";
+    "quarantine.restore" => "Restaurer", "Restore";
+    "quarantine.purge" => "Supprimer", "Delete";
+    "quarantine.confirm_purge" => "Confirmer la suppression", "Confirm deletion";
+    "quarantine.restored" => "\nRestauré depuis la quarantaine: {}\n",
+        "\nRestored from quarantine: {}\n";
+    "quarantine.restore_error" => "**** Impossible de restaurer {}: {} ****\n",
+        "**** Could not restore {}: {} ****\n";
+    "quarantine.purge_error" => "**** Impossible de supprimer {} de la quarantaine: {} ****\n",
+        "**** Could not delete {} from the quarantine: {} ****\n";
+    "quarantine.unavailable" => "Quarantaine indisponible: dossier de données introuvable",
+        "Quarantine unavailable: no data folder found";
+    "quarantine.list_error" => "Impossible de lire la quarantaine: {}",
+        "Could not read the quarantine: {}";
+    "quarantine.read_error" => "**** Erreur lors de la lecture de la quarantaine: {} ****\n",
+        "**** Error while reading the quarantine: {} ****\n";
+    "quarantine.expired_purged" =>
+        "\nQuarantaine: {} éléments expirés supprimés définitivement\n",
+        "\nQuarantine: {} expired items permanently deleted\n";
+
+    // *** PLANIFICATION ***
+    "schedule.title" => "Suppression planifiée", "Scheduled purge";
+    "schedule.console_title" => "SUPPRESSION PLANIFIÉE", "SCHEDULED PURGE";
+    "schedule.skipped" => "**** Suppression planifiée ignorée: {} ****\n",
+        "**** Scheduled purge skipped: {} ****\n";
+    "schedule.not_purged" => "{} éléments non supprimés", "{} items not deleted";
+    "schedule.failed_detail" => "L'opération s'est arrêtée sans résultat",
+        "The operation stopped without a result";
+    "schedule.next_run" => "Prochaine exécution: {}", "Next run: {}";
+    "schedule.run_summary" => "{} éléments, {}", "{} items, {}";
+    "schedule.limit_items" => "{} éléments sélectionnés (limite: {})",
+        "{} items selected (limit: {})";
+    "schedule.limit_size" => "{} sélectionnés (limite: {} Go)", "{} selected (limit: {} GB)";
+    "run.success" => "Succès", "Success";
+    "run.partial_failure" => "Échec partiel", "Partial failure";
+    "run.failed" => "Échec", "Failed";
+    "run.skipped" => "Ignorée", "Skipped";
+
+    // *** HISTORIQUE ***
+    "history.title" => "Historique des suppressions", "Deletion history";
+    "history.search" => "Rechercher", "Search";
+    "history.reload" => "Recharger", "Reload";
+    "history.read_error" => "Impossible de lire le journal: {}", "Could not read the log: {}";
+    "history.empty" => "Aucune suppression enregistrée.", "No deletion recorded.";
+    "history.entry" => "{}  {}  {} éléments supprimés ({})", "{}  {}  {} items deleted ({})";
+    "history.entry_quarantined" => ", {} en quarantaine", ", {} quarantined";
+    "history.policy" => "Politique: {}", "Policy: {}";
+    "trigger.interface" => "Interface", "Interface";
+    "trigger.scheduled" => "Planifiée", "Scheduled";
+    "trigger.command_line" => "Ligne de commande", "Command line";
+    "trigger.quarantine_expiry" => "Fin de quarantaine", "Quarantine expiry";
+    "policy.keep" => "Préserver {}", "Keep {}";
+    "policy.size_quota" => ", taille max. {} Go", ", max. size {} GB";
+    "policy.protections" => ", {} protections", ", {} protection rules";
+    "policy.quarantine" => ", quarantaine de {} jours", ", {}-day quarantine";
+
+    // *** LIGNE DE COMMANDE ***
+    "cli.unknown_command" => "Commande inconnue: {}", "Unknown command: {}";
+    "cli.unknown_option" => "Option inconnue: {}", "Unknown option: {}";
+    "cli.expects_path" => "L'option {} attend un chemin de fichier",
+        "Option {} expects a file path";
+    "cli.config_error" => "Impossible de lire la configuration {}: {}",
+        "Unable to read the configuration {}: {}";
+    "cli.expects_profile" => "L'option {} attend un nom de profil",
+        "Option {} expects a profile name";
+    "cli.unknown_profile" => "Profil inconnu: {}", "Unknown profile: {}";
+    "cli.expects_days" => "L'option {} attend un nombre de jours",
+        "Option {} expects a number of days";
+    "cli.expects_size" => "L'option {} attend une taille en Go", "Option {} expects a size in GB";
+    "cli.expects_pattern" => "L'option {} attend un motif", "Option {} expects a pattern";
+    "cli.expects_format" => "L'option {} attend un format", "Option {} expects a format";
+    "cli.expects_time" => "L'option {} attend une heure (HH:MM)",
+        "Option {} expects a time (HH:MM)";
+    "cli.expects_number" => "L'option {} attend un nombre", "Option {} expects a number";
+    "cli.expects_language" => "L'option {} attend une langue (fr, en)",
+        "Option {} expects a language (fr, en)";
+    "cli.expects_date_format" => "L'option {} attend un format de date",
+        "Option {} expects a date format";
+    "cli.invalid_date_format" => "Format de date invalide: {}", "Invalid date format: {}";
+    "cli.expects_retention" => "L'option {} attend une durée (30min, 12h, 90d, 2w, 6mo)",
+        "Option {} expects a duration (30min, 12h, 90d, 2w, 6mo)";
+    "cli.invalid_retention" => "Durée invalide (30min, 12h, 90d, 2w, 6mo): {}",
+        "Invalid duration (30min, 12h, 90d, 2w, 6mo): {}";
+    "cli.invalid_days" => "Nombre de jours invalide: {}", "Invalid number of days: {}";
+    "cli.invalid_size" => "Taille invalide: {}", "Invalid size: {}";
+    "cli.invalid_time" => "Heure invalide (HH:MM): {}", "Invalid time (HH:MM): {}";
+    "cli.invalid_item_count" => "Nombre d'éléments invalide: {}", "Invalid number of items: {}";
+    "cli.unknown_format" => "Format inconnu: {}", "Unknown format: {}";
+    "cli.unknown_language" => "Langue inconnue: {}", "Unknown language: {}";
+    "cli.confirm_required" => "La suppression définitive doit être confirmée avec --yes",
+        "Permanent deletion must be confirmed with --yes";
+    "cli.report_write_error" => "Erreur lors de l'écriture du rapport: {}",
+        "Error while writing the report: {}";
+    "cli.scheduled_run" => "\nExécution planifiée: {} ({} éléments, {}) {}\n",
+        "\nScheduled run: {} ({} items, {}) {}\n";
+    "cli.usage" => "\
+Utilisation:
+  windows_trash_manager                          Lance l'interface graphique
+  windows_trash_manager analyze [--days N]       Liste les éléments à supprimer
+  windows_trash_manager purge [--days N] --yes   Supprime définitivement ces éléments
+  windows_trash_manager schedule --yes           Supprime ces éléments à intervalle régulier
+  windows_trash_manager help                     Affiche cette aide
+
+Options:
+  --config FICHIER     Lit les réglages d'un fichier TOML (les autres options les remplacent)
+  --profile NOM        Applique un profil du fichier de configuration
+  --compare NOM        Compare l'analyse avec un autre profil (analyze)
+  --days N, -d N       Nombre de jours à préserver
+  --keep D             Durée à préserver: 30min, 12h, 90d, 2w ou 6mo (remplace --days)
+  --max-size G         Taille maximale de la corbeille en Go (les plus anciens sont supprimés)
+  --protect-name GLOB  Protège les éléments dont le nom correspond au motif
+  --protect-folder DIR Protège les éléments provenant de ce dossier
+  --protect-ext EXT    Protège les éléments ayant cette extension
+  --format F, -f F     Format de sortie: text (défaut), json, jsonl, csv
+  --quarantine N       Place les éléments en quarantaine N jours au lieu de les supprimer
+  --at HH:MM           Heure des exécutions planifiées (défaut: 03:00)
+  --every N            Nombre de jours entre deux exécutions (défaut: 1)
+  --max-items N        Ignore une exécution qui sélectionne plus de N éléments (défaut: 500)
+  --max-total G        Ignore une exécution qui sélectionne plus de G Go (défaut: 10)
+  --lang L             Langue des messages: fr, en (défaut: langue du système)
+  --date-format F      Format des dates: iso, locale (défaut) ou un format strftime
+  --utc                Affiche les dates en UTC
+  --relative           Affiche les dates relatives (« il y a 3 jours »)
+  --yes, -y            Confirme la suppression définitive
+
+Codes de sortie:
+  0  Succès
+  1  Arguments invalides
+  2  Échec partiel (certains éléments n'ont pas pu être traités)
+  3  Erreur lors de la lecture de la corbeille",
+        "\
+Usage:
+  windows_trash_manager                          Starts the graphical interface
+  windows_trash_manager analyze [--days N]       Lists the items to delete
+  windows_trash_manager purge [--days N] --yes   Permanently deletes these items
+  windows_trash_manager schedule --yes           Deletes these items at regular intervals
+  windows_trash_manager help                     Shows this help
+
+Options:
+  --config FILE        Reads the settings from a TOML file (the other options override them)
+  --profile NAME       Applies a profile from the configuration file
+  --compare NAME       Compares the analysis with another profile (analyze)
+  --days N, -d N       Number of days to keep
+  --keep D             Duration to keep: 30min, 12h, 90d, 2w or 6mo (replaces --days)
+  --max-size G         Maximum trash size in GB (the oldest items are deleted)
+  --protect-name GLOB  Protects items whose name matches the pattern
+  --protect-folder DIR Protects items coming from this folder
+  --protect-ext EXT    Protects items with this extension
+  --format F, -f F     Output format: text (default), json, jsonl, csv
+  --quarantine N       Quarantines items for N days instead of deleting them
+  --at HH:MM           Time of the scheduled runs (default: 03:00)
+  --every N            Number of days between two runs (default: 1)
+  --max-items N        Skips a run that selects more than N items (default: 500)
+  --max-total G        Skips a run that selects more than G GB (default: 10)
+  --lang L             Language of the messages: fr, en (default: system language)
+  --date-format F      Date format: iso, locale (default) or a strftime format
+  --utc                Shows dates in UTC
+  --relative           Shows relative dates (\"3 days ago\")
+  --yes, -y            Confirms the permanent deletion
+
+Exit codes:
+  0  Success
+  1  Invalid arguments
+  2  Partial failure (some items could not be processed)
+  3  Error while reading the trash";
+
+    // *** NOTICE ***
+    "notice" => "
+📖 NOTICE D'UTILISATION 📖\n\n\
+▶ Préserver ◀\n\
+  Permet de spécifier la durée (en minutes, heures, jours, semaines ou mois) \
+  pendant laquelle les éléments restent dans la corbeille avant d'être \
+  supprimés définitivement.\n\
+   Exemple: Si défini sur 5 jours, les éléments supprimés il y a plus de 5 jours \
+  seront sélectionnés pour être supprimés de la corbeille.\n\
+▶ Taille max. ◀\n\
+  Si activée, les éléments les plus anciens sont également sélectionnés jusqu'à \
+  ce que la corbeille repasse sous la taille indiquée (en Go).\n\
+▶ Protections ◀\n\
+  Règles (nom, dossier d'origine, extension) qui empêchent la suppression des \
+  éléments correspondants. Ils sont listés à part lors de l'analyse.\n\
+▶ Analyser ◀\n\
+  Ce bouton permet de lister les éléments supprimés au-delà du nombre de jours \
+  défini pour la corbeille. Les éléments peuvent ensuite être cochés dans le \
+  tableau pour ne supprimer ou restaurer que ceux-ci.\n\
+▶ Supprimer définitivement ◀\n\
+  Ce bouton permet de supprimer définitivement les éléments qui ont dépassé le \
+  nombre de jours défini pour la corbeille. Les éléments en échec peuvent être \
+  réessayés depuis le tableau des résultats.\n\
+▶ Quarantaine ◀\n\
+  Si elle est activée dans Fichier > Paramètres…, les éléments ne sont pas \
+  supprimés mais déplacés dans un dossier de l'application. Ils restent \
+  restaurables depuis Fichier > Quarantaine… pendant la durée choisie, puis sont \
+  supprimés définitivement.\n\
+▶ Suppression planifiée ◀\n\
+  Dans Fichier > Paramètres…, la politique peut être appliquée automatiquement \
+  chaque jour à l'heure choisie tant que l'application est ouverte (ou avec la \
+  commande `schedule`). Une exécution qui dépasse les limites de sécurité est \
+  ignorée, sans entrée dans l'historique: elle figure parmi les dernières \
+  exécutions affichées dans Fichier > Paramètres….\n\
+▶ Historique ◀\n\
+  Chaque suppression définitive est enregistrée dans un journal d'audit. \
+  Fichier > Historique… permet de parcourir les exécutions passées et d'y \
+  rechercher un élément par nom ou par chemin.\n\
+▶ Langue ◀\n\
+  La langue de l'interface se choisit dans Fichier > Paramètres…. Par défaut, \
+  c'est celle du système.\n\
+🖊 Remarque: Une fenêtre de confirmation récapitule toujours les éléments \
+  concernés avant la suppression définitive manuelle.\n\n",
+        "
+📖 USER GUIDE 📖\n\n\
+▶ Keep ◀\n\
+  Sets how long (in minutes, hours, days, weeks or months) items stay in the \
+  trash before they are permanently deleted.\n\
+   Example: If set to 5 days, items deleted more than 5 days ago will be \
+  selected for deletion from the trash.\n\
+▶ Max. size ◀\n\
+  If enabled, the oldest items are also selected until the trash is back \
+  under the given size (in GB).\n\
+▶ Protections ◀\n\
+  Rules (name, original folder, extension) that prevent matching items from \
+  being deleted. They are listed separately during the analysis.\n\
+▶ Analyze ◀\n\
+  This button lists the items deleted longer ago than the number of days set \
+  for the trash. Items can then be checked in the table to delete or restore \
+  only those.\n\
+▶ Delete permanently ◀\n\
+  This button permanently deletes the items older than the number of days set \
+  for the trash. Failed items can be retried from the results table.\n\
+▶ Quarantine ◀\n\
+  If enabled in File > Settings…, items are not deleted but moved to a folder \
+  of the application. They can be restored from File > Quarantine… for the \
+  chosen duration, then they are permanently deleted.\n\
+▶ Scheduled purge ◀\n\
+  In File > Settings…, the policy can be applied automatically every day at \
+  the chosen time while the application is open (or with the `schedule` \
+  command). A run that exceeds the safety limits is skipped without an entry \
+  in the history: it appears among the recent runs shown in File > \
+  Settings….\n\
+▶ History ◀\n\
+  Every permanent deletion is recorded in an audit log. File > History… lets \
+  you browse past runs and search them for an item by name or path.\n\
+▶ Language ◀\n\
+  The interface language is chosen in File > Settings…. By default, it is the \
+  system language.\n\
+🖊 Note: A confirmation window always summarizes the affected items before a \
+  manual permanent deletion.\n\n";
+}
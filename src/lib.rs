@@ -1,22 +1,42 @@
 #![warn(clippy::all, rust_2021_compatibility)]
 mod app;
-pub use app::TemplateApp;
+mod audit;
+mod config_file;
+mod console;
+mod dashboard;
+mod diagnostics;
+pub use app::{Outcome, TemplateApp};
+pub use diagnostics::ConsoleLayer;
+mod backend;
+pub use backend::{MemoryTrash, SystemTrash, TrashBackend};
+pub mod cli;
 mod consts;
+mod format;
+mod history;
+mod i18n;
+pub use i18n::Language;
+mod profile;
+mod purge;
+mod quarantine;
+pub mod report;
+mod retention;
+pub mod rules;
+mod scheduler;
+mod table;
+mod worker;
 
-pub const NOTICE: &str = "
-📖 NOTICE D'UTILISATION 📖\n\n\
-▶ Préserver X jours ◀\n\
-  Permet de spécifier le nombre de jours durant lesquels les éléments restent \
-  dans la corbeille avant d'être supprimés définitivement.\n\
-   Exemple: Si défini sur 5 jours, les éléments supprimés il y a plus de 5 jours \
-  seront automatiquement supprimés de la corbeille.\n\
-▶ Analyser ◀\n\
-  Ce bouton permet de lister les éléments supprimés au-delà du nombre de jours \
-  défini pour la corbeille.\n\
-▶ Supprimer définitivement ◀\n\
-  Ce bouton permet de supprimer définitivement les éléments qui ont dépassé le \
-  nombre de jours défini pour la corbeille.\n\
-🖊 Remarque: Il n'est pas nécessaire d'effectuer une analyse au préalable.\n\n";
+/// Notice d'utilisation affichée au démarrage, dans la langue courante.
+pub fn notice() -> &'static str {
+    i18n::tr("notice")
+}
+
+/// Dossier de données de l'application, à côté de la persistance d'eframe.
+/// `None` si le système n'en fournit pas: aucun chemin relatif n'est utilisé.
+pub(crate) fn data_dir() -> Option<std::path::PathBuf> {
+    directories_next::ProjectDirs::from("", "", consts::APP_NAME)
+        .map(|dirs| dirs.data_dir().to_path_buf())
+        .filter(|directory| directory.is_absolute())
+}
 
 pub struct GitHubInfo {
     url: String,
@@ -32,10 +52,14 @@ impl Default for GitHubInfo {
     }
 }
 
+/// Tampon circulaire de taille fixe: une fois plein, chaque ajout remplace
+/// l'élément le plus ancien. Les indices partent du plus ancien élément.
 pub struct CircularBuffer<T> {
     buffer: Vec<T>,
+    capacity: usize,
+    /// Position du plus ancien élément dans `buffer`. Toujours nulle tant que
+    /// le tampon n'est pas plein.
     head: usize,
-    tail: usize,
 }
 
 impl<T> CircularBuffer<T> {
@@ -46,46 +70,147 @@ impl<T> CircularBuffer<T> {
         );
         Self {
             buffer: Vec::with_capacity(capacity),
+            capacity,
             head: 0,
-            tail: 0,
         }
     }
 
     pub fn push(&mut self, item: T) {
-        assert!(
-            self.buffer.capacity() > 0,
-            "[CircularBuffer] Le tampon doit avoir une capacité supérieure à zéro."
-        );
-        if self.buffer.len() < self.buffer.capacity() {
+        if self.buffer.len() < self.capacity {
             self.buffer.push(item);
-            self.tail += 1;
         } else {
             self.buffer[self.head] = item;
-            self.head = (self.head + 1) % self.buffer.capacity();
-            self.tail = (self.tail + 1) % self.buffer.capacity();
+            self.head = (self.head + 1) % self.capacity;
         }
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
-        let len = self.buffer.len();
-        let cap = self.buffer.capacity();
-        let tail = self.tail;
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
 
-        self.buffer
-            .iter()
-            .skip(self.head)
-            .chain(self.buffer.iter().take(tail))
-            .cycle()
-            .take(len.min(cap))
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Élément à la position `index`, 0 étant le plus ancien.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.buffer.len() {
+            Some(&self.buffer[(self.head + index) % self.buffer.len()])
+        } else {
+            None
+        }
+    }
+
+    /// Du plus ancien au plus récent.
+    pub fn iter(&self) -> CircularBufferIter<'_, T> {
+        let (back, front) = self.buffer.split_at(self.head);
+        CircularBufferIter {
+            front: front.iter(),
+            back: back.iter(),
+        }
+    }
+
+    /// Change la capacité en gardant les éléments les plus récents.
+    pub fn resize(&mut self, capacity: usize) {
+        assert!(
+            capacity > 0,
+            "[CircularBuffer] La capacité doit être supérieure à zéro."
+        );
+        self.make_contiguous();
+        if self.buffer.len() > capacity {
+            self.buffer.drain(..self.buffer.len() - capacity);
+        }
+        if capacity < self.capacity {
+            self.buffer.shrink_to(capacity);
+        }
+        self.capacity = capacity;
+    }
+
+    /// Retire tous les éléments, du plus ancien au plus récent.
+    pub fn drain(&mut self) -> std::vec::Drain<'_, T> {
+        self.make_contiguous();
+        self.buffer.drain(..)
     }
 
     pub fn clear(&mut self) {
         self.buffer.clear();
         self.head = 0;
-        self.tail = 0;
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.buffer.is_empty()
+    /// Remet les éléments dans l'ordre au début de `buffer`.
+    fn make_contiguous(&mut self) {
+        self.buffer.rotate_left(self.head);
+        self.head = 0;
+    }
+}
+
+impl<T> std::ops::Index<usize> for CircularBuffer<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(item) => item,
+            None => panic!(
+                "[CircularBuffer] Indice {} hors limites (longueur {}).",
+                index,
+                self.len()
+            ),
+        }
     }
 }
+
+impl<T> Extend<T> for CircularBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.push(item);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CircularBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = CircularBufferIter<'a, T>;
+
+    fn into_iter(self) -> CircularBufferIter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Itérateur sur un [`CircularBuffer`], du plus ancien au plus récent.
+pub struct CircularBufferIter<'a, T> {
+    /// Du plus ancien élément à la fin de `buffer`.
+    front: std::slice::Iter<'a, T>,
+    /// Du début de `buffer` au plus récent élément.
+    back: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for CircularBufferIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.front.next().or_else(|| self.back.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for CircularBufferIter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.back.next_back().or_else(|| self.front.next_back())
+    }
+}
+
+impl<'a, T> ExactSizeIterator for CircularBufferIter<'a, T> {
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<'a, T> std::iter::FusedIterator for CircularBufferIter<'a, T> {}
@@ -4,10 +4,27 @@
 mod assets;
 use crate::assets::*;
 use egui::Vec2;
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::prelude::*;
 
 fn main() -> eframe::Result<()> {
-    // Log to stdout (if you run with `RUST_LOG=debug`).
-    tracing_subscriber::fmt::init();
+    // Mode ligne de commande si des arguments sont fournis.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        // Les logs vont sur stderr pour ne pas polluer les résultats.
+        tracing_subscriber::fmt()
+            .with_writer(std::io::stderr)
+            .with_env_filter(env_filter())
+            .init();
+        std::process::exit(windows_trash_manager::cli::run(&args));
+    }
+
+    // Log to stdout (if you run with `RUST_LOG=debug`), and to the in-app
+    // console above the level chosen in the settings.
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(env_filter()))
+        .with(windows_trash_manager::ConsoleLayer.with_filter(LevelFilter::DEBUG))
+        .init();
 
     // Set up native GUI integration options.
     let native_options = native_options();
@@ -19,6 +36,13 @@ fn main() -> eframe::Result<()> {
     )
 }
 
+/// Niveau des logs lu dans `RUST_LOG`, `info` par défaut.
+fn env_filter() -> EnvFilter {
+    EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .from_env_lossy()
+}
+
 fn app_icon() -> eframe::IconData {
     let icon = image::io::Reader::new(std::io::Cursor::new(WTM_ICON))
         .with_guessed_format()
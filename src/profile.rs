@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+use crate::app::ConfigApp;
+use crate::audit::AuditPolicy;
+use crate::i18n::tr;
+use crate::retention::Retention;
+use crate::rules::ProtectionRule;
+
+/// Politique de rétention nommée (« Agressive avant sauvegarde », « Prudente »…).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub name: String,
+    pub time_threshold: Retention,
+    pub size_quota_enabled: bool,
+    pub size_quota_gb: f64,
+    pub protection_rules: Vec<ProtectionRule>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::capture(String::new(), &ConfigApp::default())
+    }
+}
+
+impl Profile {
+    /// Profil reprenant la politique en vigueur.
+    pub fn capture(name: String, config_app: &ConfigApp) -> Self {
+        Self {
+            name,
+            time_threshold: config_app.time_threshold,
+            size_quota_enabled: config_app.size_quota_enabled,
+            size_quota_gb: config_app.size_quota_gb,
+            protection_rules: config_app.protection_rules.clone(),
+        }
+    }
+
+    /// Remplace la politique en vigueur par celle du profil.
+    pub fn apply(&self, config_app: &mut ConfigApp) {
+        config_app.time_threshold = self.time_threshold;
+        config_app.size_quota_enabled = self.size_quota_enabled;
+        config_app.size_quota_gb = self.size_quota_gb;
+        config_app.protection_rules = self.protection_rules.clone();
+    }
+
+    pub fn describe(&self) -> String {
+        AuditPolicy {
+            retention: self.time_threshold,
+            size_quota_gb: self.size_quota_enabled.then_some(self.size_quota_gb),
+            protection_rules: self.protection_rules.clone(),
+            quarantine_days: None,
+        }
+        .describe()
+    }
+}
+
+impl ConfigApp {
+    pub fn profile(&self, name: &str) -> Option<&Profile> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    /// Nom de la politique en vigueur, pour l'affichage.
+    pub fn policy_name(&self) -> &str {
+        self.active_profile
+            .as_deref()
+            .unwrap_or_else(|| tr("profile.none"))
+    }
+
+    /// Active un profil et applique sa politique. Retourne `false` si le profil n'existe pas.
+    pub fn switch_profile(&mut self, name: &str) -> bool {
+        let Some(profile) = self.profile(name).cloned() else {
+            return false;
+        };
+        profile.apply(self);
+        self.active_profile = Some(profile.name);
+        true
+    }
+
+    /// Enregistre la politique en vigueur sous `name`, en remplaçant un profil
+    /// du même nom, puis l'active.
+    pub fn save_profile(&mut self, name: &str) {
+        let profile = Profile::capture(name.to_owned(), self);
+        match self
+            .profiles
+            .iter_mut()
+            .find(|existing| existing.name == name)
+        {
+            Some(existing) => *existing = profile,
+            None => self.profiles.push(profile),
+        }
+        self.active_profile = Some(name.to_owned());
+    }
+
+    pub fn remove_profile(&mut self, name: &str) {
+        self.profiles.retain(|profile| profile.name != name);
+        if self.active_profile.as_deref() == Some(name) {
+            self.active_profile = None;
+        }
+    }
+
+    /// Reporte les modifications de la politique en vigueur dans le profil actif.
+    pub fn sync_active_profile(&mut self) {
+        let Some(name) = self.active_profile.clone() else {
+            return;
+        };
+        let captured = Profile::capture(name, self);
+        if let Some(profile) = self
+            .profiles
+            .iter_mut()
+            .find(|profile| profile.name == captured.name)
+        {
+            if *profile != captured {
+                *profile = captured;
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use std::collections::HashSet;
+use std::ffi::OsString;
+
+use tracing::{debug, error, instrument, warn};
+use trash::TrashItem;
+
+use crate::backend::TrashBackend;
+use crate::worker::Progress;
+
+/// Nombre d'éléments supprimés par appel à la corbeille. Un appel ne peut pas
+/// être interrompu: la taille des lots borne l'attente d'une annulation.
+pub const BATCH_SIZE: usize = 64;
+
+/// Supprime définitivement `items` par lots et retourne un résultat par
+/// élément, dans le même ordre.
+///
+/// Si un lot échoue, il est coupé en deux jusqu'à isoler les éléments
+/// fautifs, pour que chacun garde sa propre erreur. L'annulation est vérifiée
+/// avant chaque lot et chaque moitié de lot; seuls les premiers éléments ont
+/// alors un résultat.
+#[instrument(name = "batches", skip_all, fields(items = items.len()))]
+pub fn purge_in_batches(
+    backend: &dyn TrashBackend,
+    items: &[TrashItem],
+    progress: &Progress,
+) -> Vec<Result<(), trash::Error>> {
+    // Éléments présents avant la suppression: un élément déjà absent garde
+    // son erreur, même s'il faisait partie d'un lot en échec.
+    let present: Option<HashSet<OsString>> = (items.len() > 1)
+        .then(|| backend.list().ok())
+        .flatten()
+        .map(|listed| listed.into_iter().map(|item| item.id).collect());
+
+    let mut purge = BatchPurge {
+        backend,
+        progress,
+        total: items.len(),
+        results: Vec::with_capacity(items.len()),
+    };
+    progress.set(0, items.len());
+    for batch in items.chunks(BATCH_SIZE) {
+        if progress.is_cancelled() {
+            break;
+        }
+        purge.batch(batch, false);
+    }
+    let mut results = purge.results;
+
+    // Un lot en échec a pu supprimer une partie de ses éléments avant
+    // l'erreur: ceux qui étaient présents et ne sont plus dans la corbeille
+    // sont bien supprimés.
+    let reconcilable = |item: &TrashItem, result: &Result<(), trash::Error>, in_failed_batch| {
+        in_failed_batch
+            && result.is_err()
+            && present
+                .as_ref()
+                .map_or(false, |present| present.contains(&item.id))
+    };
+    if items
+        .iter()
+        .zip(&results)
+        .any(|(item, (result, in_failed_batch))| reconcilable(item, result, *in_failed_batch))
+    {
+        if let Ok(remaining) = backend.list() {
+            let remaining: HashSet<OsString> = remaining.into_iter().map(|item| item.id).collect();
+            for (item, (result, in_failed_batch)) in items.iter().zip(results.iter_mut()) {
+                if reconcilable(item, result, *in_failed_batch) && !remaining.contains(&item.id) {
+                    debug!(item = %item.name, "supprimé par un lot en échec");
+                    *result = Ok(());
+                }
+            }
+        }
+    }
+    results.into_iter().map(|(result, _)| result).collect()
+}
+
+/// Suppression en cours: résultat de chaque élément traité, et si cet
+/// élément faisait partie d'un lot en échec.
+struct BatchPurge<'a> {
+    backend: &'a dyn TrashBackend,
+    progress: &'a Progress,
+    total: usize,
+    results: Vec<(Result<(), trash::Error>, bool)>,
+}
+
+impl BatchPurge<'_> {
+    /// `in_failed_batch` indique que `batch` est une moitié d'un lot en échec.
+    fn batch(&mut self, batch: &[TrashItem], in_failed_batch: bool) {
+        match self.backend.purge(batch.to_vec()) {
+            Ok(()) => {
+                debug!(size = batch.len(), "lot supprimé");
+                self.results
+                    .extend(batch.iter().map(|_| (Ok(()), in_failed_batch)));
+            }
+            Err(e) if batch.len() == 1 => {
+                error!(item = %batch[0].name, error = %e, "suppression impossible");
+                self.results.push((Err(e), in_failed_batch));
+            }
+            Err(e) => {
+                warn!(size = batch.len(), error = %e, "échec du lot, découpage en deux");
+                let (left, right) = batch.split_at(batch.len() / 2);
+                for half in [left, right] {
+                    if self.progress.is_cancelled() {
+                        return;
+                    }
+                    self.batch(half, true);
+                }
+                return;
+            }
+        }
+        self.progress.set(self.results.len(), self.total);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::Duration;
+
+    use super::*;
+    use crate::backend::MemoryTrash;
+
+    /// Corbeille dont les éléments verrouillés font échouer tout lot qui les
+    /// contient, après avoir éventuellement supprimé les autres.
+    struct LockingTrash {
+        inner: MemoryTrash,
+        locked: HashSet<String>,
+        partial: bool,
+        /// Annulation demandée au premier lot en échec.
+        cancel_on_failure: Option<Progress>,
+        calls: Mutex<Vec<usize>>,
+    }
+
+    impl LockingTrash {
+        fn new(names: &[&str], locked: &[&str]) -> (Self, Vec<TrashItem>) {
+            let inner = MemoryTrash::new();
+            let items = names
+                .iter()
+                .map(|name| inner.seed(name, "/tmp", Duration::days(60), 10))
+                .collect();
+            let trash = Self {
+                inner,
+                locked: locked.iter().map(|name| name.to_string()).collect(),
+                partial: false,
+                cancel_on_failure: None,
+                calls: Mutex::new(Vec::new()),
+            };
+            (trash, items)
+        }
+
+        fn remaining(&self) -> Vec<String> {
+            let mut names: Vec<String> = self
+                .inner
+                .list()
+                .unwrap()
+                .into_iter()
+                .map(|item| item.name)
+                .collect();
+            names.sort();
+            names
+        }
+    }
+
+    impl TrashBackend for LockingTrash {
+        fn list(&self) -> Result<Vec<TrashItem>, trash::Error> {
+            self.inner.list()
+        }
+
+        fn purge(&self, items: Vec<TrashItem>) -> Result<(), trash::Error> {
+            self.calls.lock().unwrap().push(items.len());
+            let (locked, free): (Vec<TrashItem>, Vec<TrashItem>) = items
+                .into_iter()
+                .partition(|item| self.locked.contains(&item.name));
+            if locked.is_empty() {
+                return self.inner.purge(free);
+            }
+            if self.partial {
+                let _ = self.inner.purge(free);
+            }
+            if let Some(progress) = &self.cancel_on_failure {
+                progress.cancel();
+            }
+            Err(trash::Error::Os {
+                code: 32,
+                description: format!("{} est verrouillé", locked[0].name),
+            })
+        }
+
+        fn restore(&self, items: Vec<TrashItem>) -> Result<(), trash::Error> {
+            self.inner.restore(items)
+        }
+
+        fn metadata(&self, item: &TrashItem) -> Result<trash::TrashItemMetadata, trash::Error> {
+            self.inner.metadata(item)
+        }
+
+        fn delete(&self, path: &std::path::Path) -> Result<(), trash::Error> {
+            self.inner.delete(path)
+        }
+    }
+
+    fn failed(results: &[Result<(), trash::Error>], items: &[TrashItem]) -> Vec<String> {
+        items
+            .iter()
+            .zip(results)
+            .filter(|(_, result)| result.is_err())
+            .map(|(item, _)| item.name.clone())
+            .collect()
+    }
+
+    /// Élément qui n'est pas ou plus dans la corbeille.
+    fn absent(model: &TrashItem) -> TrashItem {
+        TrashItem {
+            id: OsString::from("absent"),
+            name: "déjà parti".to_owned(),
+            ..model.clone()
+        }
+    }
+
+    const NAMES: [&str; 8] = ["a", "b", "c", "d", "e", "f", "g", "h"];
+
+    #[test]
+    fn bisection_attributes_errors_to_the_failing_items() {
+        let (trash, items) = LockingTrash::new(&NAMES, &["c", "g"]);
+        let results = purge_in_batches(&trash, &items, &Progress::default());
+
+        assert_eq!(results.len(), items.len());
+        assert_eq!(failed(&results, &items), ["c", "g"]);
+        assert_eq!(trash.remaining(), ["c", "g"]);
+    }
+
+    #[test]
+    fn items_deleted_by_a_failed_batch_are_reported_as_purged() {
+        let (mut trash, items) = LockingTrash::new(&NAMES, &["b"]);
+        trash.partial = true;
+        let results = purge_in_batches(&trash, &items, &Progress::default());
+
+        // Les autres éléments ont disparu avec le premier lot, puis leurs
+        // nouvelles tentatives ont échoué: ils sont bien supprimés.
+        assert_eq!(failed(&results, &items), ["b"]);
+        assert_eq!(trash.remaining(), ["b"]);
+    }
+
+    #[test]
+    fn items_already_gone_keep_their_error() {
+        let (trash, mut items) = LockingTrash::new(&NAMES, &[]);
+        items.insert(3, absent(&items[0]));
+        let results = purge_in_batches(&trash, &items, &Progress::default());
+
+        assert_eq!(failed(&results, &items), ["déjà parti"]);
+        assert!(matches!(
+            results[3],
+            Err(trash::Error::CouldNotAccess { .. })
+        ));
+        assert!(trash.remaining().is_empty());
+    }
+
+    #[test]
+    fn a_single_item_failure_is_not_reconciled() {
+        let (trash, items) = LockingTrash::new(&["a"], &[]);
+        let results = purge_in_batches(&trash, &[absent(&items[0])], &Progress::default());
+
+        assert!(results[0].is_err());
+        assert_eq!(trash.remaining(), ["a"]);
+    }
+
+    #[test]
+    fn cancellation_stops_the_bisection() {
+        let (mut trash, items) = LockingTrash::new(&NAMES, &["a"]);
+        let progress = Progress::default();
+        trash.cancel_on_failure = Some(progress.clone());
+        let results = purge_in_batches(&trash, &items, &progress);
+
+        // Le lot complet a échoué: aucune moitié n'est tentée après l'annulation.
+        assert!(results.is_empty());
+        assert_eq!(*trash.calls.lock().unwrap(), [NAMES.len()]);
+        assert_eq!(trash.remaining().len(), NAMES.len());
+    }
+
+    #[test]
+    fn large_purges_are_split_into_batches() {
+        let names: Vec<String> = (0..BATCH_SIZE * 2 + 5).map(|i| i.to_string()).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let (trash, items) = LockingTrash::new(&names, &[]);
+        let results = purge_in_batches(&trash, &items, &Progress::default());
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(*trash.calls.lock().unwrap(), [BATCH_SIZE, BATCH_SIZE, 5]);
+    }
+}
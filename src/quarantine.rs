@@ -0,0 +1,518 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::{Duration, Local};
+use serde::{Deserialize, Serialize};
+use tracing::{instrument, warn};
+use trash::TrashItem;
+
+use crate::backend::TrashBackend;
+use crate::i18n::trf;
+use crate::report::{Candidate, ErrorKind, ItemError};
+use crate::worker::Progress;
+
+/// Nom du fichier ou dossier mis en quarantaine, dans le dossier de l'élément.
+const DATA: &str = "data";
+/// Métadonnées de l'élément, dans le dossier de l'élément.
+const META: &str = "meta.json";
+
+/// Élément conservé en quarantaine avant sa suppression définitive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantinedItem {
+    /// Nom du dossier de l'élément dans la quarantaine.
+    pub id: String,
+    pub name: String,
+    pub original_parent: PathBuf,
+    /// Date de mise à la corbeille (horodatage).
+    pub time_deleted: i64,
+    /// Date de mise en quarantaine (horodatage).
+    pub quarantined_at: i64,
+    pub size: Option<u64>,
+}
+
+impl QuarantinedItem {
+    pub fn original_path(&self) -> PathBuf {
+        self.original_parent.join(&self.name)
+    }
+
+    /// Date à partir de laquelle l'élément peut être supprimé définitivement.
+    pub fn expires_at(&self, days: u16) -> i64 {
+        self.quarantined_at + Duration::days(days.into()).num_seconds()
+    }
+
+    /// Élément équivalent de la corbeille, pour les rapports.
+    pub fn to_trash_item(&self) -> TrashItem {
+        TrashItem {
+            id: self.id.clone().into(),
+            name: self.name.clone(),
+            original_parent: self.original_parent.clone(),
+            time_deleted: self.time_deleted,
+        }
+    }
+}
+
+/// Dossier géré par l'application où les éléments sortis de la corbeille
+/// attendent une seconde période avant d'être supprimés définitivement.
+///
+/// Chaque élément a son propre dossier contenant le fichier (ou dossier)
+/// d'origine et ses métadonnées.
+#[derive(Clone)]
+pub struct Quarantine {
+    root: PathBuf,
+    ops: FileOps,
+}
+
+/// Opérations de fichiers utilisées pour déplacer les éléments. Les tests les
+/// remplacent pour simuler un autre volume ou un échec.
+#[derive(Clone, Copy)]
+struct FileOps {
+    rename: fn(&Path, &Path) -> io::Result<()>,
+    copy_file: fn(&Path, &Path) -> io::Result<()>,
+    remove: fn(&Path) -> io::Result<()>,
+}
+
+impl FileOps {
+    const SYSTEM: FileOps = FileOps {
+        rename: |from, to| fs::rename(from, to),
+        copy_file: |from, to| fs::copy(from, to).map(|_| ()),
+        remove: remove_path,
+    };
+}
+
+/// Étape d'un déplacement qui a échoué.
+enum MoveError {
+    /// La source est intacte et la copie partielle a été retirée.
+    Copy(io::Error),
+    /// La copie est complète mais la source n'a pas pu être entièrement supprimée.
+    SourceRemoval(io::Error),
+}
+
+impl Quarantine {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            ops: FileOps::SYSTEM,
+        }
+    }
+
+    /// Quarantaine du dossier de données de l'application, `None` si ce
+    /// dossier est introuvable.
+    pub fn in_data_dir() -> Option<Self> {
+        crate::data_dir().map(|directory| Self::new(directory.join("quarantine")))
+    }
+
+    /// Sort les éléments de la corbeille pour les placer en quarantaine.
+    /// Retourne un résultat par élément traité, dans le même ordre.
+    #[instrument(name = "quarantine", skip_all, fields(items = candidates.len()))]
+    pub fn admit(
+        &self,
+        backend: &dyn TrashBackend,
+        candidates: &[Candidate],
+        progress: &Progress,
+    ) -> Vec<Result<(), ItemError>> {
+        let mut results = Vec::with_capacity(candidates.len());
+        progress.set(0, candidates.len());
+        for candidate in candidates {
+            if progress.is_cancelled() {
+                break;
+            }
+            let result = self.admit_one(backend, candidate);
+            if let Err(error) = &result {
+                warn!(item = %candidate.item.name, error = %error.message, "mise en quarantaine impossible");
+            }
+            results.push(result);
+            progress.set(results.len(), candidates.len());
+        }
+        results
+    }
+
+    fn admit_one(
+        &self,
+        backend: &dyn TrashBackend,
+        candidate: &Candidate,
+    ) -> Result<(), ItemError> {
+        let item = &candidate.item;
+        // La corbeille ne donne pas accès à ses fichiers: l'élément est
+        // restauré à son emplacement d'origine puis déplacé aussitôt.
+        let original_path = item.original_path();
+        if original_path.exists() {
+            return Err(ItemError::new(
+                ErrorKind::NameCollision,
+                trf("error.already_exists", &[&original_path.display()]),
+            ));
+        }
+        backend
+            .restore(vec![item.clone()])
+            .map_err(|e| ItemError::from(&e))?;
+
+        let quarantined = QuarantinedItem {
+            id: quarantine_id(item),
+            name: item.name.clone(),
+            original_parent: item.original_parent.clone(),
+            time_deleted: item.time_deleted,
+            quarantined_at: Local::now().timestamp(),
+            size: candidate.size,
+        };
+        let directory = self.root.join(&quarantined.id);
+        // Les métadonnées sont écrites d'abord: une copie complète est ainsi
+        // toujours visible et restaurable depuis la quarantaine.
+        let moved = fs::create_dir_all(&directory)
+            .and_then(|()| {
+                let meta = serde_json::to_vec_pretty(&quarantined).map_err(io::Error::from)?;
+                fs::write(directory.join(META), meta)
+            })
+            .map_err(MoveError::Copy)
+            .and_then(|()| self.move_path(&original_path, &directory.join(DATA)));
+        match moved {
+            Ok(()) => Ok(()),
+            Err(MoveError::Copy(e)) => {
+                // La source est intacte: elle retourne à la corbeille plutôt
+                // que de rester restaurée.
+                let _ = fs::remove_dir_all(&directory);
+                if let Err(trash_error) = backend.delete(&original_path) {
+                    warn!(item = %item.name, error = %trash_error, "remise à la corbeille impossible");
+                }
+                Err(ItemError::from(&e))
+            }
+            Err(MoveError::SourceRemoval(e)) => {
+                // La copie en quarantaine est la seule complète: elle est conservée.
+                let mut error = ItemError::from(&e);
+                error.message = trf("error.quarantine_source", &[&original_path.display(), &e]);
+                Err(error)
+            }
+        }
+    }
+
+    /// Éléments en quarantaine, du plus ancien au plus récent.
+    pub fn list(&self) -> io::Result<Vec<QuarantinedItem>> {
+        let entries = match fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut items = Vec::new();
+        for entry in entries {
+            let meta = match fs::read(entry?.path().join(META)) {
+                Ok(meta) => meta,
+                Err(_) => continue,
+            };
+            if let Ok(item) = serde_json::from_slice::<QuarantinedItem>(&meta) {
+                items.push(item);
+            }
+        }
+        items.sort_by_key(|item| item.quarantined_at);
+        Ok(items)
+    }
+
+    /// Remet l'élément à son emplacement d'origine.
+    pub fn restore(&self, item: &QuarantinedItem) -> Result<(), ItemError> {
+        let target = item.original_path();
+        if target.exists() {
+            return Err(ItemError::new(
+                ErrorKind::NameCollision,
+                trf("error.already_exists", &[&target.display()]),
+            ));
+        }
+        let directory = self.root.join(&item.id);
+        fs::create_dir_all(&item.original_parent).map_err(|e| ItemError::from(&e))?;
+        match self.move_path(&directory.join(DATA), &target) {
+            // L'élément est restauré: il ne reste que le dossier de la quarantaine.
+            Ok(()) | Err(MoveError::SourceRemoval(_)) => {
+                fs::remove_dir_all(&directory).map_err(|e| ItemError::from(&e))
+            }
+            Err(MoveError::Copy(e)) => Err(ItemError::from(&e)),
+        }
+    }
+
+    /// Supprime définitivement l'élément.
+    pub fn purge(&self, item: &QuarantinedItem) -> Result<(), ItemError> {
+        fs::remove_dir_all(self.root.join(&item.id)).map_err(|e| ItemError::from(&e))
+    }
+
+    /// Éléments dont la période de quarantaine est écoulée.
+    pub fn expired(&self, days: u16) -> io::Result<Vec<QuarantinedItem>> {
+        let now = Local::now().timestamp();
+        Ok(self
+            .list()?
+            .into_iter()
+            .filter(|item| item.expires_at(days) <= now)
+            .collect())
+    }
+
+    /// Déplace un fichier ou un dossier, en le copiant si la destination est
+    /// sur un autre volume.
+    fn move_path(&self, from: &Path, to: &Path) -> Result<(), MoveError> {
+        if (self.ops.rename)(from, to).is_ok() {
+            return Ok(());
+        }
+        if let Err(e) = self.copy_recursive(from, to) {
+            let _ = remove_path(to);
+            return Err(MoveError::Copy(e));
+        }
+        (self.ops.remove)(from).map_err(MoveError::SourceRemoval)
+    }
+
+    fn copy_recursive(&self, from: &Path, to: &Path) -> io::Result<()> {
+        if from.is_dir() {
+            fs::create_dir_all(to)?;
+            for entry in fs::read_dir(from)? {
+                let entry = entry?;
+                self.copy_recursive(&entry.path(), &to.join(entry.file_name()))?;
+            }
+            Ok(())
+        } else {
+            (self.ops.copy_file)(from, to)
+        }
+    }
+}
+
+/// Nom unique du dossier d'un élément dans la quarantaine.
+fn quarantine_id(item: &TrashItem) -> String {
+    let mut hasher = DefaultHasher::new();
+    item.id.hash(&mut hasher);
+    format!("{}-{:016x}", Local::now().timestamp(), hasher.finish())
+}
+
+fn remove_path(path: &Path) -> io::Result<()> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use trash::TrashItemMetadata;
+
+    use super::*;
+    use crate::backend::MemoryTrash;
+    use crate::report::SelectionRule;
+
+    /// Dossier temporaire supprimé à la fin du test.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "windows_trash_manager-quarantine-{}-{}",
+                std::process::id(),
+                name
+            ));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    const FILES: [&str; 3] = ["a.txt", "b.txt", "c.txt"];
+
+    /// Corbeille dont les éléments sont des dossiers de trois fichiers,
+    /// recréés sur le disque lors de la restauration.
+    struct DiskTrash(MemoryTrash);
+
+    impl TrashBackend for DiskTrash {
+        fn list(&self) -> Result<Vec<TrashItem>, trash::Error> {
+            self.0.list()
+        }
+
+        fn purge(&self, items: Vec<TrashItem>) -> Result<(), trash::Error> {
+            self.0.purge(items)
+        }
+
+        fn restore(&self, items: Vec<TrashItem>) -> Result<(), trash::Error> {
+            self.0.restore(items.clone())?;
+            for item in items {
+                let path = item.original_path();
+                fs::create_dir_all(&path).unwrap();
+                for file in FILES {
+                    fs::write(path.join(file), file).unwrap();
+                }
+            }
+            Ok(())
+        }
+
+        fn metadata(&self, item: &TrashItem) -> Result<TrashItemMetadata, trash::Error> {
+            self.0.metadata(item)
+        }
+
+        fn delete(&self, path: &Path) -> Result<(), trash::Error> {
+            self.0.delete(path)?;
+            fs::remove_dir_all(path).map_err(|e| trash::Error::Unknown {
+                description: e.to_string(),
+            })
+        }
+    }
+
+    fn other_volume(_: &Path, _: &Path) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Other, "autre volume"))
+    }
+
+    const ACROSS_VOLUMES: FileOps = FileOps {
+        rename: other_volume,
+        ..FileOps::SYSTEM
+    };
+
+    /// La copie de `b.txt` échoue.
+    const COPY_FAILS: FileOps = FileOps {
+        rename: other_volume,
+        copy_file: |from, to| {
+            if from.ends_with("b.txt") {
+                return Err(io::Error::new(io::ErrorKind::PermissionDenied, "illisible"));
+            }
+            fs::copy(from, to).map(|_| ())
+        },
+        ..FileOps::SYSTEM
+    };
+
+    /// La suppression de la source s'arrête sur `b.txt`, verrouillé.
+    const REMOVAL_FAILS: FileOps = FileOps {
+        rename: other_volume,
+        remove: |path| {
+            for entry in fs::read_dir(path)? {
+                let entry = entry?;
+                if entry.file_name() != "b.txt" {
+                    fs::remove_file(entry.path())?;
+                }
+            }
+            Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "verrouillé",
+            ))
+        },
+        ..FileOps::SYSTEM
+    };
+
+    struct Setup {
+        temp: TempDir,
+        quarantine: Quarantine,
+        trash: DiskTrash,
+        candidate: Candidate,
+    }
+
+    fn setup(name: &str, ops: FileOps) -> Setup {
+        let temp = TempDir::new(name);
+        let trash = DiskTrash(MemoryTrash::new());
+        let item = trash
+            .0
+            .seed("projet", temp.0.join("documents"), Duration::days(60), 30);
+        let quarantine = Quarantine {
+            root: temp.0.join("quarantine"),
+            ops,
+        };
+        let candidate = Candidate {
+            item,
+            rule: SelectionRule::Age,
+            size: Some(30),
+        };
+        Setup {
+            temp,
+            quarantine,
+            trash,
+            candidate,
+        }
+    }
+
+    fn admit(setup: &Setup) -> Result<(), ItemError> {
+        let mut results = setup.quarantine.admit(
+            &setup.trash,
+            std::slice::from_ref(&setup.candidate),
+            &Progress::default(),
+        );
+        assert_eq!(results.len(), 1);
+        results.remove(0)
+    }
+
+    fn files(path: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(path)
+            .map(|entries| {
+                entries
+                    .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn admits_and_restores_an_item() {
+        let setup = setup("admit", FileOps::SYSTEM);
+        let original = setup.candidate.item.original_path();
+        admit(&setup).unwrap();
+
+        assert!(!original.exists());
+        assert!(setup.trash.0.is_empty());
+        let items = setup.quarantine.list().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].original_path(), original);
+        assert_eq!(
+            files(&setup.quarantine.root.join(&items[0].id).join(DATA)),
+            FILES
+        );
+
+        setup.quarantine.restore(&items[0]).unwrap();
+        assert_eq!(files(&original), FILES);
+        assert!(setup.quarantine.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn moves_across_volumes_by_copying() {
+        let setup = setup("volumes", ACROSS_VOLUMES);
+        let original = setup.candidate.item.original_path();
+        admit(&setup).unwrap();
+
+        assert!(!original.exists());
+        let items = setup.quarantine.list().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(
+            files(&setup.quarantine.root.join(&items[0].id).join(DATA)),
+            FILES
+        );
+    }
+
+    #[test]
+    fn a_failed_copy_returns_the_item_to_the_trash() {
+        let setup = setup("copy", COPY_FAILS);
+        let error = admit(&setup).unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::PermissionDenied);
+        // Rien ne reste dans la quarantaine et l'élément est de retour dans
+        // la corbeille.
+        assert!(files(&setup.quarantine.root).is_empty());
+        assert!(!setup.candidate.item.original_path().exists());
+        let trashed = setup.trash.list().unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].name, "projet");
+        assert_eq!(trashed[0].original_parent, setup.temp.0.join("documents"));
+    }
+
+    #[test]
+    fn a_failed_source_removal_keeps_the_quarantine_copy() {
+        let setup = setup("removal", REMOVAL_FAILS);
+        let original = setup.candidate.item.original_path();
+        let error = admit(&setup).unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::PermissionDenied);
+        assert!(error.message.contains(&original.display().to_string()));
+        // La copie complète reste en quarantaine, restaurable, et ce qui
+        // reste de l'original n'est pas remis à la corbeille.
+        let items = setup.quarantine.list().unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(
+            files(&setup.quarantine.root.join(&items[0].id).join(DATA)),
+            FILES
+        );
+        assert_eq!(files(&original), ["b.txt"]);
+        assert!(setup.trash.0.is_empty());
+    }
+}
@@ -0,0 +1,428 @@
+use std::io::Write;
+
+use serde::{Deserialize, Serialize};
+use trash::TrashItem;
+
+use crate::app::Outcome;
+use crate::format::format_date_absolute;
+use crate::i18n::tr;
+use crate::rules::ProtectionRule;
+
+/// Type d'opération à l'origine d'un rapport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportKind {
+    Analysis,
+    Purge,
+    Restore,
+}
+
+/// Statut d'un élément dans un rapport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemStatus {
+    /// Élément sélectionné par l'analyse, rien n'a encore été fait.
+    Selected,
+    Purged,
+    Restored,
+    /// Élément sorti de la corbeille et conservé en quarantaine.
+    Quarantined,
+    Failed,
+    /// Élément qui aurait été sélectionné mais qu'une règle de protection épargne.
+    Protected,
+}
+
+/// Règle de rétention ayant sélectionné un élément.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionRule {
+    /// L'élément est plus ancien que le nombre de jours à préserver.
+    Age,
+    /// L'élément est parmi les plus anciens à supprimer pour respecter la taille maximale.
+    SizeQuota,
+}
+
+impl SelectionRule {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SelectionRule::Age => tr("selection.age"),
+            SelectionRule::SizeQuota => tr("selection.size_quota"),
+        }
+    }
+}
+
+/// Cause d'un échec, pour regrouper les erreurs dans les statistiques.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// L'élément n'est plus dans la corbeille.
+    NotFound,
+    PermissionDenied,
+    /// Le fichier est verrouillé par un autre programme.
+    InUse,
+    /// Un élément du même nom existe déjà à l'emplacement d'origine.
+    NameCollision,
+    Other,
+}
+
+/// Codes système d'un fichier verrouillé par un autre processus.
+#[cfg(windows)]
+const IN_USE_CODES: [i32; 2] = [32, 33]; // ERROR_SHARING_VIOLATION, ERROR_LOCK_VIOLATION
+#[cfg(not(windows))]
+const IN_USE_CODES: [i32; 2] = [16, 26]; // EBUSY, ETXTBSY
+
+impl ErrorKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ErrorKind::NotFound => tr("error_kind.not_found"),
+            ErrorKind::PermissionDenied => tr("error_kind.permission_denied"),
+            ErrorKind::InUse => tr("error_kind.in_use"),
+            ErrorKind::NameCollision => tr("error_kind.name_collision"),
+            ErrorKind::Other => tr("error_kind.other"),
+        }
+    }
+
+    pub fn of(error: &trash::Error) -> Self {
+        match error {
+            trash::Error::CouldNotAccess { .. } => ErrorKind::NotFound,
+            trash::Error::RestoreCollision { .. } | trash::Error::RestoreTwins { .. } => {
+                ErrorKind::NameCollision
+            }
+            trash::Error::Os { code, .. } => Self::from_os_code(*code),
+            #[cfg(all(
+                unix,
+                not(target_os = "macos"),
+                not(target_os = "ios"),
+                not(target_os = "android")
+            ))]
+            trash::Error::FileSystem { source, .. } => Self::from_io(source),
+            _ => ErrorKind::Other,
+        }
+    }
+
+    fn from_os_code(code: i32) -> Self {
+        // Les HRESULT Win32 (0x8007xxxx) contiennent le code d'erreur système.
+        let code = if (code as u32) & 0xFFFF_0000 == 0x8007_0000 {
+            code & 0xFFFF
+        } else {
+            code
+        };
+        Self::from_io(&std::io::Error::from_raw_os_error(code))
+    }
+
+    fn from_io(error: &std::io::Error) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::NotFound => ErrorKind::NotFound,
+            std::io::ErrorKind::PermissionDenied => ErrorKind::PermissionDenied,
+            _ if error
+                .raw_os_error()
+                .map_or(false, |code| IN_USE_CODES.contains(&code)) =>
+            {
+                ErrorKind::InUse
+            }
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+/// Erreur rencontrée sur un élément: sa cause et le message d'origine.
+#[derive(Debug, Clone)]
+pub struct ItemError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl ItemError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<&std::io::Error> for ItemError {
+    fn from(error: &std::io::Error) -> Self {
+        Self::new(ErrorKind::from_io(error), error.to_string())
+    }
+}
+
+impl From<&trash::Error> for ItemError {
+    fn from(error: &trash::Error) -> Self {
+        Self::new(ErrorKind::of(error), error.to_string())
+    }
+}
+
+/// Une ligne de rapport, identique pour tous les formats d'export.
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemRecord {
+    pub name: String,
+    pub original_path: String,
+    /// Date de mise à la corbeille, au format et dans le fuseau choisis.
+    pub time_deleted: String,
+    pub status: ItemStatus,
+    pub error: Option<String>,
+    pub error_kind: Option<ErrorKind>,
+    pub rule: Option<SelectionRule>,
+    /// Taille en octets, si elle est connue.
+    pub size: Option<u64>,
+    /// Règle de protection qui a épargné l'élément.
+    pub protected_by: Option<String>,
+    /// Élément d'origine, pour pouvoir agir à nouveau dessus après l'analyse.
+    #[serde(skip)]
+    pub item: TrashItem,
+}
+
+impl ItemRecord {
+    pub fn new(item: &TrashItem, status: ItemStatus, error: Option<ItemError>) -> Self {
+        Self {
+            name: item.name.clone(),
+            original_path: item.original_path().to_string_lossy().into_owned(),
+            time_deleted: format_date_absolute(item.time_deleted),
+            status,
+            error_kind: error.as_ref().map(|error| error.kind),
+            error: error.map(|error| error.message),
+            rule: None,
+            size: None,
+            protected_by: None,
+            item: item.clone(),
+        }
+    }
+
+    pub fn with_candidate(mut self, candidate: &Candidate) -> Self {
+        self.rule = Some(candidate.rule);
+        self.size = candidate.size;
+        self
+    }
+
+    /// Reconstruit l'élément retenu à partir d'une ligne d'analyse.
+    pub fn to_candidate(&self) -> Candidate {
+        Candidate {
+            item: self.item.clone(),
+            rule: self.rule.unwrap_or(SelectionRule::Age),
+            size: self.size,
+        }
+    }
+
+    pub fn with_protection(mut self, rule: &ProtectionRule) -> Self {
+        self.protected_by = Some(rule.describe());
+        self
+    }
+}
+
+/// Élément retenu par la politique de rétention.
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub item: TrashItem,
+    pub rule: SelectionRule,
+    pub size: Option<u64>,
+}
+
+/// Résultat de la sélection: éléments à traiter et éléments épargnés.
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    pub candidates: Vec<Candidate>,
+    pub protected: Vec<(TrashItem, ProtectionRule)>,
+}
+
+/// Résultat structuré d'une analyse ou d'une suppression.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub kind: ReportKind,
+    pub outcome: Outcome,
+    pub items: Vec<ItemRecord>,
+}
+
+impl Report {
+    pub fn new(kind: ReportKind) -> Self {
+        Self {
+            kind,
+            outcome: Outcome::Success,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn with_outcome(mut self, outcome: Outcome) -> Self {
+        self.outcome = outcome;
+        self
+    }
+
+    /// Écrit le rapport dans le format demandé.
+    pub fn write_to<W: Write>(&self, format: ExportFormat, mut writer: W) -> std::io::Result<()> {
+        match format {
+            ExportFormat::Json => {
+                serde_json::to_writer_pretty(&mut writer, self).map_err(std::io::Error::from)?;
+                writeln!(writer)
+            }
+            ExportFormat::JsonLines => {
+                for item in &self.items {
+                    serde_json::to_writer(&mut writer, item).map_err(std::io::Error::from)?;
+                    writeln!(writer)?;
+                }
+                Ok(())
+            }
+            ExportFormat::Csv => {
+                let mut csv_writer = csv::Writer::from_writer(writer);
+                for item in &self.items {
+                    csv_writer.serialize(item).map_err(std::io::Error::from)?;
+                }
+                csv_writer.flush()
+            }
+        }
+    }
+
+    pub fn render(&self, format: ExportFormat) -> String {
+        let mut buffer = Vec::new();
+        self.write_to(format, &mut buffer)
+            .expect("L'écriture en mémoire ne peut pas échouer");
+        String::from_utf8(buffer).expect("Le rapport est toujours en UTF-8")
+    }
+}
+
+/// Formats d'export disponibles pour les rapports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ExportFormat {
+    Json,
+    JsonLines,
+    Csv,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [
+        ExportFormat::Json,
+        ExportFormat::JsonLines,
+        ExportFormat::Csv,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "JSON",
+            ExportFormat::JsonLines => "JSON Lines",
+            ExportFormat::Csv => "CSV",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::JsonLines => "jsonl",
+            ExportFormat::Csv => "csv",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(ExportFormat::Json),
+            "jsonl" => Some(ExportFormat::JsonLines),
+            "csv" => Some(ExportFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+
+    use super::*;
+
+    const COLUMNS: [&str; 9] = [
+        "name",
+        "original_path",
+        "time_deleted",
+        "status",
+        "error",
+        "error_kind",
+        "rule",
+        "size",
+        "protected_by",
+    ];
+
+    /// Rapport dont les noms contiennent les séparateurs de chaque format.
+    fn report() -> Report {
+        let item = |name: &str| TrashItem {
+            id: OsString::from(name),
+            name: name.to_owned(),
+            original_parent: PathBuf::from("/tmp/dossier, \"à part\""),
+            time_deleted: 1_700_000_000,
+        };
+        let mut report = Report::new(ReportKind::Purge);
+        let mut purged = ItemRecord::new(&item("a,b \"c\".txt"), ItemStatus::Purged, None);
+        purged.rule = Some(SelectionRule::SizeQuota);
+        purged.size = Some(42);
+        report.items.push(purged);
+        report.items.push(ItemRecord::new(
+            &item("ligne\nsuivante\té.txt"),
+            ItemStatus::Failed,
+            Some(ItemError::new(ErrorKind::InUse, "occupé; \"verrouillé\"")),
+        ));
+        report
+    }
+
+    #[test]
+    fn csv_keeps_columns_and_separators() {
+        let report = report();
+        let csv = report.render(ExportFormat::Csv);
+
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        let headers: Vec<String> = reader
+            .headers()
+            .unwrap()
+            .iter()
+            .map(str::to_owned)
+            .collect();
+        assert_eq!(headers, COLUMNS);
+        let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(&rows[0][0], "a,b \"c\".txt");
+        assert_eq!(&rows[0][1], report.items[0].original_path);
+        assert_eq!(&rows[0][3], "purged");
+        assert_eq!(&rows[0][6], "size_quota");
+        assert_eq!(&rows[0][7], "42");
+        assert_eq!(&rows[0][4], "");
+        assert_eq!(&rows[1][0], "ligne\nsuivante\té.txt");
+        assert_eq!(&rows[1][4], "occupé; \"verrouillé\"");
+        assert_eq!(&rows[1][5], "in_use");
+    }
+
+    #[test]
+    fn json_round_trips_the_items() {
+        let report = report();
+        let json: serde_json::Value =
+            serde_json::from_str(&report.render(ExportFormat::Json)).unwrap();
+
+        assert_eq!(json["kind"], "purge");
+        let items = json["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        let keys: Vec<&str> = items[0]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        let mut columns = COLUMNS.to_vec();
+        columns.sort_unstable();
+        assert_eq!(keys, columns);
+        assert_eq!(items[0]["name"], "a,b \"c\".txt");
+        assert_eq!(items[0]["size"], 42);
+        assert_eq!(items[1]["name"], "ligne\nsuivante\té.txt");
+        assert_eq!(items[1]["error"], "occupé; \"verrouillé\"");
+        assert_eq!(items[1]["error_kind"], "in_use");
+    }
+
+    #[test]
+    fn json_lines_writes_one_item_per_line() {
+        let report = report();
+        let lines = report.render(ExportFormat::JsonLines);
+
+        let items: Vec<serde_json::Value> = lines
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["name"], "a,b \"c\".txt");
+        assert_eq!(items[1]["name"], "ligne\nsuivante\té.txt");
+        assert_eq!(items[1]["status"], "failed");
+    }
+}
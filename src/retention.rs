@@ -0,0 +1,289 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Local, Months};
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::i18n::{tr, trf};
+
+/// Unité de la durée de rétention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeUnit {
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    /// Mois calendaires: le même jour du mois, `amount` mois plus tôt.
+    Months,
+}
+
+impl TimeUnit {
+    pub const ALL: [TimeUnit; 5] = [
+        TimeUnit::Minutes,
+        TimeUnit::Hours,
+        TimeUnit::Days,
+        TimeUnit::Weeks,
+        TimeUnit::Months,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeUnit::Minutes => tr("time_unit.minutes"),
+            TimeUnit::Hours => tr("time_unit.hours"),
+            TimeUnit::Days => tr("time_unit.days"),
+            TimeUnit::Weeks => tr("time_unit.weeks"),
+            TimeUnit::Months => tr("time_unit.months"),
+        }
+    }
+
+    /// Suffixe de la ligne de commande (`30min`, `12h`, `90d`, `2w`, `6mo`).
+    fn suffix(&self) -> &'static str {
+        match self {
+            TimeUnit::Minutes => "min",
+            TimeUnit::Hours => "h",
+            TimeUnit::Days => "d",
+            TimeUnit::Weeks => "w",
+            TimeUnit::Months => "mo",
+        }
+    }
+}
+
+/// Durée pendant laquelle les éléments restent dans la corbeille.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Retention {
+    pub amount: u32,
+    pub unit: TimeUnit,
+}
+
+impl Default for Retention {
+    fn default() -> Self {
+        Self::days(30)
+    }
+}
+
+impl Retention {
+    pub fn days(amount: u32) -> Self {
+        Self {
+            amount,
+            unit: TimeUnit::Days,
+        }
+    }
+
+    /// Date avant laquelle les éléments mis à la corbeille sont sélectionnés,
+    /// `None` si la durée remonte avant les dates représentables.
+    pub fn cutoff(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        let amount = i64::from(self.amount);
+        match self.unit {
+            TimeUnit::Minutes => now.checked_sub_signed(Duration::minutes(amount)),
+            TimeUnit::Hours => now.checked_sub_signed(Duration::hours(amount)),
+            TimeUnit::Days => now.checked_sub_signed(Duration::days(amount)),
+            TimeUnit::Weeks => now.checked_sub_signed(Duration::weeks(amount)),
+            TimeUnit::Months => now.checked_sub_months(Months::new(self.amount)),
+        }
+    }
+}
+
+impl fmt::Display for Retention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let key = match (self.unit, self.amount == 1) {
+            (TimeUnit::Minutes, _) => "retention.minutes",
+            (TimeUnit::Hours, _) => "retention.hours",
+            (TimeUnit::Days, true) => "retention.day",
+            (TimeUnit::Days, false) => "retention.days",
+            (TimeUnit::Weeks, true) => "retention.week",
+            (TimeUnit::Weeks, false) => "retention.weeks",
+            (TimeUnit::Months, true) => "retention.month",
+            (TimeUnit::Months, false) => "retention.months",
+        };
+        f.write_str(&trf(key, &[&self.amount]))
+    }
+}
+
+impl FromStr for Retention {
+    type Err = ();
+
+    /// Lit une durée de la ligne de commande: un nombre suivi d'une unité
+    /// (`30min`, `12h`, `90d`, `2w`, `6mo`). Sans unité, ce sont des jours.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let split = text
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(text.len());
+        let (amount, suffix) = text.split_at(split);
+        let amount = amount.parse::<u32>().map_err(|_| ())?;
+        let unit = match suffix {
+            "" => TimeUnit::Days,
+            suffix => TimeUnit::ALL
+                .into_iter()
+                .find(|unit| unit.suffix() == suffix)
+                .ok_or(())?,
+        };
+        (amount >= 1).then_some(Self { amount, unit }).ok_or(())
+    }
+}
+
+/// Accepte aussi l'ancien format persisté, un simple nombre de jours (`u8`).
+impl<'de> Deserialize<'de> for Retention {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "snake_case")]
+        enum Field {
+            Amount,
+            Unit,
+            #[serde(other)]
+            Other,
+        }
+
+        struct RetentionVisitor;
+
+        impl<'de> Visitor<'de> for RetentionVisitor {
+            type Value = Retention;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(
+                    "a positive number of days or a retention with a positive amount and a unit",
+                )
+            }
+
+            fn visit_u64<E: de::Error>(self, days: u64) -> Result<Retention, E> {
+                u32::try_from(days)
+                    .ok()
+                    .filter(|&days| days >= 1)
+                    .map(Retention::days)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(days), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, days: i64) -> Result<Retention, E> {
+                u32::try_from(days)
+                    .ok()
+                    .filter(|&days| days >= 1)
+                    .map(Retention::days)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(days), &self))
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Retention, A::Error> {
+                let mut retention = Retention::default();
+                while let Some(field) = map.next_key()? {
+                    match field {
+                        Field::Amount => retention.amount = map.next_value()?,
+                        Field::Unit => retention.unit = map.next_value()?,
+                        Field::Other => {
+                            map.next_value::<de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                // Une durée nulle sélectionnerait toute la corbeille.
+                if retention.amount == 0 {
+                    return Err(de::Error::invalid_value(de::Unexpected::Unsigned(0), &self));
+                }
+                Ok(retention)
+            }
+        }
+
+        deserializer.deserialize_any(RetentionVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::app::ConfigApp;
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct Config {
+        time_threshold: Retention,
+    }
+
+    #[test]
+    fn parses_command_line_durations() {
+        let cases = [
+            ("30min", 30, TimeUnit::Minutes),
+            ("12h", 12, TimeUnit::Hours),
+            ("90d", 90, TimeUnit::Days),
+            ("2w", 2, TimeUnit::Weeks),
+            ("6mo", 6, TimeUnit::Months),
+            (" 45 ", 45, TimeUnit::Days),
+        ];
+        for (text, amount, unit) in cases {
+            assert_eq!(text.parse(), Ok(Retention { amount, unit }), "{}", text);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        for text in ["", "0", "0d", "3x", "d", "-5", "1.5h", "99999999999"] {
+            assert_eq!(text.parse::<Retention>(), Err(()), "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn migrates_legacy_days_from_ron() {
+        let config: Config = ron::from_str("(time_threshold: 30)").unwrap();
+        assert_eq!(config.time_threshold, Retention::days(30));
+    }
+
+    #[test]
+    fn migrates_legacy_days_from_toml() {
+        let config: Config = toml::from_str("time_threshold = 30").unwrap();
+        assert_eq!(config.time_threshold, Retention::days(30));
+    }
+
+    #[test]
+    fn migrates_legacy_persisted_config() {
+        let config: ConfigApp =
+            ron::from_str("(time_threshold: 45, max_console_lines: 200)").unwrap();
+        assert_eq!(config.time_threshold, Retention::days(45));
+        assert_eq!(config.max_console_lines, 200);
+    }
+
+    #[test]
+    fn round_trips_through_ron_and_toml() {
+        let config = Config {
+            time_threshold: Retention {
+                amount: 6,
+                unit: TimeUnit::Months,
+            },
+        };
+        let ron_text = ron::to_string(&config).unwrap();
+        let from_ron: Config = ron::from_str(&ron_text).unwrap();
+        assert_eq!(from_ron.time_threshold, config.time_threshold);
+
+        let toml_text = toml::to_string(&config).unwrap();
+        let from_toml: Config = toml::from_str(&toml_text).unwrap();
+        assert_eq!(from_toml.time_threshold, config.time_threshold);
+    }
+
+    #[test]
+    fn rejects_zero_retention() {
+        assert!(ron::from_str::<Config>("(time_threshold: 0)").is_err());
+        assert!(ron::from_str::<Config>("(time_threshold: (amount: 0, unit: days))").is_err());
+        assert!(toml::from_str::<Config>("time_threshold = 0").is_err());
+        assert!(
+            toml::from_str::<Config>("time_threshold = { amount = 0, unit = \"hours\" }").is_err()
+        );
+        assert!(toml::from_str::<Config>("time_threshold = -3").is_err());
+    }
+
+    #[test]
+    fn cutoff_uses_calendar_months() {
+        let now = Local.with_ymd_and_hms(2026, 3, 31, 12, 0, 0).unwrap();
+        let cutoff = Retention {
+            amount: 1,
+            unit: TimeUnit::Months,
+        }
+        .cutoff(now)
+        .unwrap();
+        assert_eq!(
+            cutoff,
+            Local.with_ymd_and_hms(2026, 2, 28, 12, 0, 0).unwrap()
+        );
+        assert_eq!(
+            Retention::days(2).cutoff(now).unwrap(),
+            Local.with_ymd_and_hms(2026, 3, 29, 12, 0, 0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::{Component, Path};
+
+use glob::{MatchOptions, Pattern};
+use serde::{Deserialize, Serialize};
+use trash::TrashItem;
+
+use crate::i18n::{tr, trf};
+
+/// Règle qui protège les éléments correspondants de la suppression.
+///
+/// Les comparaisons ne tiennent pas compte de la casse, comme l'Explorateur Windows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "pattern", rename_all = "snake_case")]
+pub enum ProtectionRule {
+    /// Motif glob sur le nom de l'élément (`*.psd`, `rapport-??.docx`).
+    NameGlob(String),
+    /// Dossier d'origine (et ses sous-dossiers).
+    FolderPrefix(String),
+    /// Extension du fichier, avec ou sans point.
+    Extension(String),
+}
+
+impl ProtectionRule {
+    /// Clés de traduction des types de règle.
+    const KINDS: [&'static str; 3] = ["rule.name_glob", "rule.folder", "rule.extension"];
+
+    /// Noms des types de règle, dans la langue courante.
+    pub fn kinds() -> [&'static str; 3] {
+        Self::KINDS.map(tr)
+    }
+
+    /// Construit une règle à partir de son index dans [`ProtectionRule::kinds`].
+    pub fn from_kind(kind: usize, pattern: &str) -> Self {
+        let pattern = pattern.trim().to_owned();
+        match kind {
+            0 => ProtectionRule::NameGlob(pattern),
+            1 => ProtectionRule::FolderPrefix(pattern),
+            _ => ProtectionRule::Extension(pattern),
+        }
+    }
+
+    pub fn kind_label(&self) -> &'static str {
+        match self {
+            ProtectionRule::NameGlob(_) => tr(Self::KINDS[0]),
+            ProtectionRule::FolderPrefix(_) => tr(Self::KINDS[1]),
+            ProtectionRule::Extension(_) => tr(Self::KINDS[2]),
+        }
+    }
+
+    pub fn pattern(&self) -> &str {
+        match self {
+            ProtectionRule::NameGlob(pattern)
+            | ProtectionRule::FolderPrefix(pattern)
+            | ProtectionRule::Extension(pattern) => pattern,
+        }
+    }
+
+    /// Vérifie que le motif est utilisable: un glob invalide ne protégerait
+    /// aucun élément.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            ProtectionRule::NameGlob(pattern) => Pattern::new(pattern)
+                .map(|_| ())
+                .map_err(|e| trf("rule.invalid_glob", &[pattern, &(e.pos + 1)])),
+            ProtectionRule::FolderPrefix(_) | ProtectionRule::Extension(_) => Ok(()),
+        }
+    }
+
+    /// Description lisible, utilisée dans les rapports.
+    pub fn describe(&self) -> String {
+        format!("{}: {}", self.kind_label(), self.pattern())
+    }
+
+    pub fn matches(&self, item: &TrashItem) -> bool {
+        match self {
+            ProtectionRule::NameGlob(pattern) => Pattern::new(pattern)
+                .map(|pattern| {
+                    pattern.matches_with(
+                        &item.name,
+                        MatchOptions {
+                            case_sensitive: false,
+                            ..MatchOptions::new()
+                        },
+                    )
+                })
+                .unwrap_or(false),
+            ProtectionRule::FolderPrefix(prefix) => {
+                let prefix = normalized_components(Path::new(prefix));
+                !prefix.is_empty()
+                    && normalized_components(&item.original_parent).starts_with(&prefix)
+            }
+            ProtectionRule::Extension(extension) => {
+                let extension = extension.trim_start_matches('.');
+                Path::new(&item.name)
+                    .extension()
+                    .map(|ext| ext.to_string_lossy().eq_ignore_ascii_case(extension))
+                    .unwrap_or(false)
+            }
+        }
+    }
+}
+
+/// Première règle qui protège l'élément, s'il y en a une.
+pub fn protecting_rule<'a>(
+    rules: &'a [ProtectionRule],
+    item: &TrashItem,
+) -> Option<&'a ProtectionRule> {
+    rules.iter().find(|rule| rule.matches(item))
+}
+
+fn normalized_components(path: &Path) -> Vec<String> {
+    path.components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .map(|component| component.as_os_str().to_string_lossy().to_lowercase())
+        .collect()
+}
@@ -0,0 +1,293 @@
+use chrono::{DateTime, Duration, Local, NaiveDateTime, NaiveTime, TimeZone};
+use serde::{Deserialize, Serialize};
+
+use crate::consts;
+use crate::format::format_size;
+use crate::i18n::{tr, trf};
+
+/// Paramètres de la suppression automatique.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduleConfig {
+    pub enabled: bool,
+    /// Heure locale de l'exécution.
+    pub hour: u8,
+    pub minute: u8,
+    /// Nombre de jours entre deux exécutions.
+    pub interval_days: u16,
+    /// Au-delà de ce nombre d'éléments, l'exécution est ignorée.
+    pub max_items: u32,
+    /// Au-delà de cette taille (en Go), l'exécution est ignorée.
+    pub max_size_gb: f64,
+}
+
+impl Default for ScheduleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hour: 3,
+            minute: 0,
+            interval_days: 1,
+            max_items: 500,
+            max_size_gb: 10.0,
+        }
+    }
+}
+
+impl ScheduleConfig {
+    /// Première exécution prévue après `last_run` (horodatage).
+    pub fn next_run(&self, last_run: i64) -> Option<DateTime<Local>> {
+        let time = NaiveTime::from_hms_opt(self.hour.into(), self.minute.into(), 0)?;
+        let last = Local.timestamp_opt(last_run, 0).single()?;
+        let mut date = last.date_naive();
+        if date.and_time(time) <= last.naive_local() {
+            date = date.succ_opt()?;
+        }
+        let date = date + Duration::days(i64::from(self.interval_days.max(1)) - 1);
+        local_datetime(date.and_time(time))
+    }
+
+    /// Raison pour laquelle une exécution dépasserait les limites de sécurité.
+    pub fn exceeds_limits(&self, count: usize, total_size: u64) -> Option<String> {
+        if count > self.max_items as usize {
+            return Some(trf("schedule.limit_items", &[&count, &self.max_items]));
+        }
+        if total_size as f64 > self.max_size_gb * consts::BYTES_PER_GB {
+            return Some(trf(
+                "schedule.limit_size",
+                &[&format_size(Some(total_size)), &self.max_size_gb],
+            ));
+        }
+        None
+    }
+}
+
+/// Heure locale correspondante, décalée d'une heure si elle tombe dans un
+/// changement d'heure.
+fn local_datetime(naive: NaiveDateTime) -> Option<DateTime<Local>> {
+    Local.from_local_datetime(&naive).earliest().or_else(|| {
+        Local
+            .from_local_datetime(&(naive + Duration::hours(1)))
+            .earliest()
+    })
+}
+
+/// Résultat d'une exécution planifiée.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    Success,
+    PartialFailure,
+    Failed,
+    /// L'exécution dépassait les limites de sécurité, rien n'a été supprimé.
+    Skipped,
+}
+
+impl RunStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RunStatus::Success => tr("run.success"),
+            RunStatus::PartialFailure => tr("run.partial_failure"),
+            RunStatus::Failed => tr("run.failed"),
+            RunStatus::Skipped => tr("run.skipped"),
+        }
+    }
+}
+
+/// Trace d'une exécution planifiée.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledRun {
+    /// Horodatage du début de l'exécution.
+    pub time: i64,
+    pub status: RunStatus,
+    /// Nombre d'éléments supprimés.
+    pub items: usize,
+    /// Taille libérée en octets.
+    pub size: u64,
+    pub detail: String,
+}
+
+/// État persistant du planificateur: dernière exécution et historique.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScheduleState {
+    /// Dernière exécution, ou moment où la planification a été activée.
+    pub last_run: Option<i64>,
+    pub runs: Vec<ScheduledRun>,
+}
+
+impl ScheduleState {
+    /// Nombre d'exécutions conservées dans l'historique.
+    const MAX_RUNS: usize = 50;
+
+    /// Indique si une exécution est due. La première exécution a lieu au
+    /// premier créneau qui suit l'activation.
+    pub fn is_due(&mut self, config: &ScheduleConfig, now: DateTime<Local>) -> bool {
+        if !config.enabled {
+            self.last_run = None;
+            return false;
+        }
+        let last_run = *self.last_run.get_or_insert(now.timestamp());
+        config
+            .next_run(last_run)
+            .map_or(false, |next_run| now >= next_run)
+    }
+
+    pub fn next_run(&self, config: &ScheduleConfig) -> Option<DateTime<Local>> {
+        if !config.enabled {
+            return None;
+        }
+        config.next_run(self.last_run?)
+    }
+
+    pub fn record(&mut self, run: ScheduledRun) {
+        self.last_run = Some(run.time);
+        self.runs.push(run);
+        if self.runs.len() > Self::MAX_RUNS {
+            let excess = self.runs.len() - Self::MAX_RUNS;
+            self.runs.drain(..excess);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2024, 1, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn config(hour: u8, minute: u8, interval_days: u16) -> ScheduleConfig {
+        ScheduleConfig {
+            enabled: true,
+            hour,
+            minute,
+            interval_days,
+            ..Default::default()
+        }
+    }
+
+    fn run(time: DateTime<Local>) -> ScheduledRun {
+        ScheduledRun {
+            time: time.timestamp(),
+            status: RunStatus::Success,
+            items: 0,
+            size: 0,
+            detail: String::new(),
+        }
+    }
+
+    #[test]
+    fn next_run_is_the_same_day_before_the_slot() {
+        let config = config(3, 30, 1);
+        assert_eq!(
+            config.next_run(at(10, 1, 0).timestamp()),
+            Some(at(10, 3, 30))
+        );
+        assert_eq!(
+            config.next_run(at(10, 3, 29).timestamp()),
+            Some(at(10, 3, 30))
+        );
+    }
+
+    #[test]
+    fn next_run_moves_to_the_next_day_at_or_after_the_slot() {
+        let config = config(3, 30, 1);
+        assert_eq!(
+            config.next_run(at(10, 3, 30).timestamp()),
+            Some(at(11, 3, 30))
+        );
+        assert_eq!(
+            config.next_run(at(10, 23, 59).timestamp()),
+            Some(at(11, 3, 30))
+        );
+        // Passage au mois suivant.
+        assert_eq!(
+            config.next_run(at(31, 12, 0).timestamp()),
+            Local.with_ymd_and_hms(2024, 2, 1, 3, 30, 0).single()
+        );
+    }
+
+    #[test]
+    fn next_run_honours_the_interval() {
+        let config = config(3, 0, 3);
+        assert_eq!(
+            config.next_run(at(10, 1, 0).timestamp()),
+            Some(at(12, 3, 0))
+        );
+        assert_eq!(
+            config.next_run(at(10, 3, 0).timestamp()),
+            Some(at(13, 3, 0))
+        );
+        // Un intervalle nul se comporte comme un jour.
+        let config = ScheduleConfig {
+            interval_days: 0,
+            ..config
+        };
+        assert_eq!(
+            config.next_run(at(10, 4, 0).timestamp()),
+            Some(at(11, 3, 0))
+        );
+    }
+
+    #[test]
+    fn first_run_follows_the_activation() {
+        let config = config(3, 0, 1);
+        let mut state = ScheduleState::default();
+
+        // Activation après le créneau du jour: rien avant le lendemain.
+        assert!(!state.is_due(&config, at(10, 8, 0)));
+        assert_eq!(state.last_run, Some(at(10, 8, 0).timestamp()));
+        assert!(!state.is_due(&config, at(11, 2, 59)));
+        assert!(state.is_due(&config, at(11, 3, 0)));
+        assert!(state.is_due(&config, at(11, 9, 0)));
+    }
+
+    #[test]
+    fn a_recorded_run_is_not_due_again_before_the_next_interval() {
+        let config = config(3, 0, 2);
+        let mut state = ScheduleState {
+            last_run: Some(at(9, 12, 0).timestamp()),
+            runs: Vec::new(),
+        };
+        assert!(state.is_due(&config, at(11, 3, 0)));
+        state.record(run(at(11, 3, 0)));
+
+        assert!(!state.is_due(&config, at(11, 3, 1)));
+        assert!(!state.is_due(&config, at(12, 3, 0)));
+        assert!(state.is_due(&config, at(13, 3, 0)));
+        assert_eq!(state.next_run(&config), Some(at(13, 3, 0)));
+    }
+
+    #[test]
+    fn disabling_the_schedule_resets_the_activation() {
+        let mut config = config(3, 0, 1);
+        let mut state = ScheduleState::default();
+        assert!(!state.is_due(&config, at(10, 8, 0)));
+
+        config.enabled = false;
+        assert!(!state.is_due(&config, at(12, 3, 0)));
+        assert_eq!(state.last_run, None);
+        assert_eq!(state.next_run(&config), None);
+
+        // Réactivée, la planification attend le créneau suivant.
+        config.enabled = true;
+        assert!(!state.is_due(&config, at(12, 4, 0)));
+        assert!(state.is_due(&config, at(13, 3, 0)));
+    }
+
+    #[test]
+    fn history_keeps_the_latest_runs() {
+        let mut state = ScheduleState::default();
+        for day in 1..=31 {
+            state.record(run(at(day, 3, 0)));
+            state.record(run(at(day, 4, 0)));
+        }
+        assert_eq!(state.runs.len(), ScheduleState::MAX_RUNS);
+        assert_eq!(state.runs.last().unwrap().time, at(31, 4, 0).timestamp());
+        assert_eq!(state.last_run, Some(at(31, 4, 0).timestamp()));
+    }
+}
@@ -0,0 +1,274 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::ffi::OsString;
+
+use crate::format::{age_in_days, format_date, format_size};
+use crate::i18n::{tr, trf};
+use crate::report::{ItemRecord, ItemStatus, Report, ReportKind};
+
+/// Colonne selon laquelle le tableau des éléments est trié.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortColumn {
+    Name,
+    Folder,
+    Date,
+    Age,
+    Size,
+    Status,
+}
+
+impl SortColumn {
+    /// Colonnes, avec la clé de traduction de leur titre et leur largeur.
+    const ALL: [(SortColumn, &'static str, f32); 6] = [
+        (SortColumn::Name, "column.name", 200.0),
+        (SortColumn::Folder, "column.original_folder", 220.0),
+        (SortColumn::Date, "column.deletion_date", 140.0),
+        (SortColumn::Age, "column.age", 60.0),
+        (SortColumn::Size, "column.size", 80.0),
+        (SortColumn::Status, "column.status", 90.0),
+    ];
+
+    fn compare(&self, a: &ItemRecord, b: &ItemRecord) -> Ordering {
+        match self {
+            SortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            SortColumn::Folder => a.item.original_parent.cmp(&b.item.original_parent),
+            SortColumn::Date => a.item.time_deleted.cmp(&b.item.time_deleted),
+            SortColumn::Age => b.item.time_deleted.cmp(&a.item.time_deleted),
+            SortColumn::Size => a.size.cmp(&b.size),
+            SortColumn::Status => status_label(a.status).cmp(status_label(b.status)),
+        }
+    }
+}
+
+pub fn status_label(status: ItemStatus) -> &'static str {
+    match status {
+        ItemStatus::Selected => tr("status.selected"),
+        ItemStatus::Purged => tr("status.purged"),
+        ItemStatus::Restored => tr("status.restored"),
+        ItemStatus::Quarantined => tr("status.quarantined"),
+        ItemStatus::Failed => tr("status.failed"),
+        ItemStatus::Protected => tr("status.protected"),
+    }
+}
+
+/// Tableau interactif des éléments d'un rapport: tri par colonne,
+/// sélection d'une ligne et détails de l'élément sélectionné.
+///
+/// Après une analyse, les éléments peuvent aussi être cochés pour n'agir
+/// que sur eux.
+pub struct ItemTable {
+    sort_column: SortColumn,
+    ascending: bool,
+    selected: Option<OsString>,
+    checked: HashSet<OsString>,
+    /// Ordre d'affichage des lignes, recalculé quand le tri ou le rapport change.
+    order: Vec<usize>,
+    dirty: bool,
+}
+
+impl Default for ItemTable {
+    fn default() -> Self {
+        Self {
+            sort_column: SortColumn::Date,
+            ascending: true,
+            selected: None,
+            checked: HashSet::new(),
+            order: Vec::new(),
+            dirty: true,
+        }
+    }
+}
+
+impl ItemTable {
+    /// À appeler quand un nouveau rapport remplace le précédent.
+    pub fn reset(&mut self) {
+        self.selected = None;
+        self.checked.clear();
+        self.dirty = true;
+    }
+
+    pub fn check_all(&mut self, report: &Report) {
+        self.checked = report
+            .items
+            .iter()
+            .map(|record| record.item.id.clone())
+            .collect();
+    }
+
+    pub fn uncheck_all(&mut self) {
+        self.checked.clear();
+    }
+
+    pub fn invert_checks(&mut self, report: &Report) {
+        self.checked = report
+            .items
+            .iter()
+            .map(|record| record.item.id.clone())
+            .filter(|id| !self.checked.contains(id))
+            .collect();
+    }
+
+    /// Éléments cochés, dans l'ordre du rapport.
+    pub fn checked_records<'a>(&self, report: &'a Report) -> Vec<&'a ItemRecord> {
+        report
+            .items
+            .iter()
+            .filter(|record| self.checked.contains(&record.item.id))
+            .collect()
+    }
+
+    pub fn selected_record<'a>(&self, report: &'a Report) -> Option<&'a ItemRecord> {
+        let selected = self.selected.as_ref()?;
+        report
+            .items
+            .iter()
+            .find(|record| &record.item.id == selected)
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selected = None;
+    }
+
+    fn sort(&mut self, report: &Report) {
+        if !self.dirty && self.order.len() == report.items.len() {
+            return;
+        }
+        self.order = (0..report.items.len()).collect();
+        let column = self.sort_column;
+        self.order
+            .sort_by(|&a, &b| column.compare(&report.items[a], &report.items[b]));
+        if !self.ascending {
+            self.order.reverse();
+        }
+        self.dirty = false;
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, report: &Report) {
+        self.sort(report);
+        let checkable = report.kind == ReportKind::Analysis;
+        let checkbox_width = 20.0;
+
+        // *** EN-TÊTE ***
+        ui.horizontal(|ui| {
+            if checkable {
+                ui.add_space(checkbox_width + ui.spacing().item_spacing.x);
+            }
+            for (column, key, width) in SortColumn::ALL {
+                let arrow = match (self.sort_column == column, self.ascending) {
+                    (true, true) => " ▲",
+                    (true, false) => " ▼",
+                    (false, _) => "",
+                };
+                let header = egui::Button::new(
+                    egui::RichText::new(format!("{}{}", tr(key), arrow)).strong(),
+                )
+                .frame(false);
+                if ui.add_sized([width, 18.0], header).clicked() {
+                    if self.sort_column == column {
+                        self.ascending = !self.ascending;
+                    } else {
+                        self.sort_column = column;
+                        self.ascending = true;
+                    }
+                    self.dirty = true;
+                }
+            }
+        });
+        ui.separator();
+
+        // *** LIGNES ***
+        let row_height = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show_rows(ui, row_height, self.order.len(), |ui, range| {
+                for &index in &self.order[range] {
+                    let record = &report.items[index];
+                    let is_selected = self.selected.as_ref() == Some(&record.item.id);
+                    let cells = [
+                        record.name.clone(),
+                        record.item.original_parent.display().to_string(),
+                        format_date(record.item.time_deleted),
+                        trf("table.age_days", &[&age_in_days(record.item.time_deleted)]),
+                        format_size(record.size),
+                        status_label(record.status).to_owned(),
+                    ];
+                    let clicked = ui
+                        .horizontal(|ui| {
+                            if checkable {
+                                let mut checked = self.checked.contains(&record.item.id);
+                                let checkbox = egui::Checkbox::new(&mut checked, "");
+                                if ui
+                                    .add_sized([checkbox_width, row_height], checkbox)
+                                    .changed()
+                                {
+                                    if checked {
+                                        self.checked.insert(record.item.id.clone());
+                                    } else {
+                                        self.checked.remove(&record.item.id);
+                                    }
+                                }
+                            }
+                            let mut clicked = false;
+                            for ((_, _, width), text) in SortColumn::ALL.iter().zip(cells) {
+                                let label = egui::SelectableLabel::new(is_selected, text);
+                                clicked |= ui.add_sized([*width, row_height], label).clicked();
+                            }
+                            clicked
+                        })
+                        .inner;
+                    if clicked {
+                        self.selected = if is_selected {
+                            None
+                        } else {
+                            Some(record.item.id.clone())
+                        };
+                    }
+                }
+            });
+    }
+}
+
+/// Panneau de détails de l'élément sélectionné.
+pub fn show_details(ui: &mut egui::Ui, record: &ItemRecord) {
+    egui::Grid::new("item_details")
+        .num_columns(2)
+        .striped(true)
+        .show(ui, |ui| {
+            let mut row = |label: &str, value: String| {
+                ui.label(egui::RichText::new(label).strong());
+                ui.label(value);
+                ui.end_row();
+            };
+            row(tr("column.name"), record.name.clone());
+            row(tr("details.original_path"), record.original_path.clone());
+            row(
+                tr("column.deletion_date"),
+                format_date(record.item.time_deleted),
+            );
+            row(
+                tr("column.age"),
+                trf(
+                    "details.age_days",
+                    &[&age_in_days(record.item.time_deleted)],
+                ),
+            );
+            row(tr("column.size"), format_size(record.size));
+            row(tr("column.status"), status_label(record.status).to_owned());
+            if let Some(rule) = record.rule {
+                row(tr("column.rule"), rule.label().to_owned());
+            }
+            if let Some(protected_by) = &record.protected_by {
+                row(tr("column.protected_by"), protected_by.clone());
+            }
+            if let Some(kind) = record.error_kind {
+                row(tr("column.cause"), kind.label().to_owned());
+            }
+            if let Some(error) = &record.error {
+                row(tr("details.error"), error.clone());
+            }
+            row(
+                tr("details.id"),
+                record.item.id.to_string_lossy().into_owned(),
+            );
+        });
+}
@@ -0,0 +1,132 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+
+use crate::app::ConsoleApp;
+use crate::console::ConsoleEntry;
+
+/// Suivi d'une opération longue: progression (éléments traités / total)
+/// et demande d'annulation entre deux éléments.
+#[derive(Clone, Default)]
+pub struct Progress {
+    cancel: Arc<AtomicBool>,
+    done: Arc<AtomicUsize>,
+    total: Arc<AtomicUsize>,
+    on_change: Option<Arc<dyn Fn() + Send + Sync>>,
+}
+
+impl Progress {
+    pub fn set(&self, done: usize, total: usize) {
+        self.done.store(done, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+        if let Some(on_change) = &self.on_change {
+            on_change();
+        }
+    }
+
+    pub fn get(&self) -> (usize, usize) {
+        (
+            self.done.load(Ordering::Relaxed),
+            self.total.load(Ordering::Relaxed),
+        )
+    }
+
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+}
+
+enum WorkerMessage<T> {
+    Log(ConsoleEntry),
+    Done(T),
+}
+
+/// État d'une opération après [`Job::poll`].
+pub enum JobStatus<T> {
+    Running,
+    Finished(T),
+    /// Le thread s'est arrêté sans résultat (panique).
+    Failed,
+}
+
+/// Opération exécutée sur un thread séparé pour ne pas bloquer l'interface.
+///
+/// Les entrées écrites dans la console du thread sont transmises à l'interface
+/// et le résultat est récupéré avec [`Job::poll`].
+pub struct Job<T> {
+    label: String,
+    cancellable: bool,
+    progress: Progress,
+    receiver: Receiver<WorkerMessage<T>>,
+}
+
+impl<T: Send + 'static> Job<T> {
+    /// Lance `task` sur un nouveau thread. `repaint` est appelé à chaque
+    /// message pour réveiller l'interface.
+    pub fn spawn<F>(
+        label: &str,
+        cancellable: bool,
+        repaint: impl Fn() + Send + Sync + 'static,
+        task: F,
+    ) -> Self
+    where
+        F: FnOnce(&mut ConsoleApp, &Progress) -> T + Send + 'static,
+    {
+        let (sender, receiver) = channel();
+        let repaint: Arc<dyn Fn() + Send + Sync> = Arc::new(repaint);
+        let progress = Progress {
+            on_change: Some(repaint.clone()),
+            ..Default::default()
+        };
+
+        let thread_progress = progress.clone();
+        thread::spawn(move || {
+            let log_sender: Sender<WorkerMessage<T>> = sender.clone();
+            let log_repaint = repaint.clone();
+            let mut console_app = ConsoleApp::forwarding(move |entry| {
+                let _ = log_sender.send(WorkerMessage::Log(entry.clone()));
+                log_repaint();
+            });
+            let output = task(&mut console_app, &thread_progress);
+            let _ = sender.send(WorkerMessage::Done(output));
+            repaint();
+        });
+
+        Self {
+            label: label.to_owned(),
+            cancellable,
+            progress,
+            receiver,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn is_cancellable(&self) -> bool {
+        self.cancellable
+    }
+
+    pub fn progress(&self) -> &Progress {
+        &self.progress
+    }
+
+    /// Transmet les messages reçus à la console et retourne le résultat
+    /// une fois l'opération terminée.
+    pub fn poll(&self, console_app: &mut ConsoleApp) -> JobStatus<T> {
+        loop {
+            match self.receiver.try_recv() {
+                Ok(WorkerMessage::Log(entry)) => console_app.push(entry),
+                Ok(WorkerMessage::Done(output)) => return JobStatus::Finished(output),
+                Err(TryRecvError::Empty) => return JobStatus::Running,
+                Err(TryRecvError::Disconnected) => return JobStatus::Failed,
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Compare `CircularBuffer` à un modèle `VecDeque` borné sur des suites
+//! d'opérations aléatoires.
+
+use std::collections::VecDeque;
+
+use proptest::prelude::*;
+use windows_trash_manager::CircularBuffer;
+
+#[derive(Debug, Clone)]
+enum Op {
+    Push(u32),
+    Extend(Vec<u32>),
+    Resize(usize),
+    Drain,
+    Clear,
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        6 => any::<u32>().prop_map(Op::Push),
+        2 => prop::collection::vec(any::<u32>(), 0..20).prop_map(Op::Extend),
+        2 => (1usize..16).prop_map(Op::Resize),
+        1 => Just(Op::Drain),
+        1 => Just(Op::Clear),
+    ]
+}
+
+/// Modèle: une file qui retire l'élément le plus ancien au-delà de `capacity`.
+struct Model {
+    items: VecDeque<u32>,
+    capacity: usize,
+}
+
+impl Model {
+    fn push(&mut self, item: u32) {
+        if self.items.len() == self.capacity {
+            self.items.pop_front();
+        }
+        self.items.push_back(item);
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        while self.items.len() > capacity {
+            self.items.pop_front();
+        }
+        self.capacity = capacity;
+    }
+}
+
+fn check(buffer: &CircularBuffer<u32>, model: &Model) -> Result<(), TestCaseError> {
+    prop_assert_eq!(buffer.len(), model.items.len());
+    prop_assert_eq!(buffer.is_empty(), model.items.is_empty());
+    prop_assert_eq!(buffer.capacity(), model.capacity);
+    prop_assert_eq!(buffer.iter().len(), model.items.len());
+
+    let forward: Vec<u32> = buffer.iter().copied().collect();
+    let expected: Vec<u32> = model.items.iter().copied().collect();
+    prop_assert_eq!(&forward, &expected);
+
+    let backward: Vec<u32> = buffer.iter().rev().copied().collect();
+    let expected_backward: Vec<u32> = model.items.iter().rev().copied().collect();
+    prop_assert_eq!(backward, expected_backward);
+
+    for (index, item) in model.items.iter().enumerate() {
+        prop_assert_eq!(buffer.get(index), Some(item));
+        prop_assert_eq!(buffer[index], *item);
+    }
+    prop_assert_eq!(buffer.get(model.items.len()), None);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn matches_vecdeque_model(
+        capacity in 1usize..16,
+        ops in prop::collection::vec(op(), 0..200),
+    ) {
+        let mut buffer = CircularBuffer::new(capacity);
+        let mut model = Model { items: VecDeque::new(), capacity };
+
+        for op in ops {
+            match op {
+                Op::Push(item) => {
+                    buffer.push(item);
+                    model.push(item);
+                }
+                Op::Extend(items) => {
+                    buffer.extend(items.iter().copied());
+                    for item in items {
+                        model.push(item);
+                    }
+                }
+                Op::Resize(capacity) => {
+                    buffer.resize(capacity);
+                    model.resize(capacity);
+                }
+                Op::Drain => {
+                    let drained: Vec<u32> = buffer.drain().collect();
+                    let expected: Vec<u32> = model.items.drain(..).collect();
+                    prop_assert_eq!(drained, expected);
+                }
+                Op::Clear => {
+                    buffer.clear();
+                    model.items.clear();
+                }
+            }
+            check(&buffer, &model)?;
+        }
+    }
+
+    #[test]
+    fn double_ended_iteration_meets_in_the_middle(
+        capacity in 1usize..16,
+        items in prop::collection::vec(any::<u32>(), 0..40),
+        from_back in prop::collection::vec(any::<bool>(), 0..40),
+    ) {
+        let mut buffer = CircularBuffer::new(capacity);
+        let mut model = Model { items: VecDeque::new(), capacity };
+        for item in items {
+            buffer.push(item);
+            model.push(item);
+        }
+
+        let mut iter = buffer.iter();
+        let mut expected = model.items.iter();
+        for back in from_back {
+            if back {
+                prop_assert_eq!(iter.next_back(), expected.next_back());
+            } else {
+                prop_assert_eq!(iter.next(), expected.next());
+            }
+            prop_assert_eq!(iter.len(), expected.len());
+        }
+        prop_assert!(iter.copied().eq(expected.copied()));
+    }
+}
+
+#[test]
+#[should_panic(expected = "hors limites")]
+fn index_out_of_bounds_panics() {
+    let mut buffer = CircularBuffer::new(2);
+    buffer.push(1);
+    let _ = buffer[1];
+}